@@ -0,0 +1,336 @@
+//! Native Docker Engine API client (via `bollard`), replacing CLI shelling for the
+//! operations the launcher needs most often. `DockerCompose`/`Service`/`Volume`
+//! mirror the on-disk compose file so the same model can be serialized to YAML
+//! (for `compose.yaml`, kept for transparency/debugging) or translated directly
+//! into bollard container-create parameters, letting the launcher bring the
+//! service up without requiring the `compose` plugin to be installed at all.
+
+use std::collections::HashMap;
+
+use bollard::container::{
+    Config, CreateContainerOptions, RemoveContainerOptions, StartContainerOptions,
+    StopContainerOptions,
+};
+use bollard::image::{ListImagesOptions, RemoveImageOptions};
+use bollard::models::{HostConfig, PortBinding};
+use bollard::Docker;
+use futures_util::stream::StreamExt;
+use serde::{Deserialize, Serialize, Serializer};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Volume {
+    #[serde(rename = "type")]
+    pub(crate) kind: String,
+    /// Host path, kept unescaped so [`Service::to_bollard_config`] can hand it
+    /// straight to bollard; only the YAML rendering (`serialize_with`) doubles
+    /// backslashes for Windows paths.
+    #[serde(serialize_with = "serialize_yaml_source_path")]
+    pub(crate) source: String,
+    pub(crate) target: String,
+}
+
+/// Doubles backslashes so Windows host paths round-trip through `compose.yaml`
+/// unambiguously; the native bollard bind spec uses the unescaped `source` field
+/// directly instead of this rendering.
+fn serialize_yaml_source_path<S>(source: &str, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&source.replace('\\', "\\\\"))
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct Service {
+    pub(crate) image: String,
+    pub(crate) container_name: String,
+    pub(crate) restart: String,
+    pub(crate) ports: Vec<String>,
+    pub(crate) volumes: Vec<Volume>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DockerCompose {
+    pub(crate) services: HashMap<String, Service>,
+}
+
+impl DockerCompose {
+    pub(crate) fn retreivr(&self) -> Option<&Service> {
+        self.services.get("retreivr")
+    }
+
+    pub(crate) fn to_yaml(&self) -> Result<String, String> {
+        serde_yaml::to_string(self).map_err(|e| e.to_string())
+    }
+}
+
+impl Service {
+    /// Translates this service into bollard container-create parameters, the
+    /// native equivalent of what `docker compose up` would do for it.
+    fn to_bollard_config(&self) -> Config<String> {
+        let binds = self
+            .volumes
+            .iter()
+            .map(|v| format!("{}:{}", v.source, v.target))
+            .collect();
+
+        let mut port_bindings = HashMap::new();
+        for port in &self.ports {
+            if let Some((host, container)) = port.split_once(':') {
+                port_bindings.insert(
+                    format!("{container}/tcp"),
+                    Some(vec![PortBinding {
+                        host_ip: Some("127.0.0.1".to_string()),
+                        host_port: Some(host.to_string()),
+                    }]),
+                );
+            }
+        }
+
+        Config {
+            image: Some(self.image.clone()),
+            host_config: Some(HostConfig {
+                binds: Some(binds),
+                port_bindings: Some(port_bindings),
+                restart_policy: Some(bollard::models::RestartPolicy {
+                    name: Some(match self.restart.as_str() {
+                        "unless-stopped" => bollard::models::RestartPolicyNameEnum::UNLESS_STOPPED,
+                        "always" => bollard::models::RestartPolicyNameEnum::ALWAYS,
+                        "on-failure" => bollard::models::RestartPolicyNameEnum::ON_FAILURE,
+                        _ => bollard::models::RestartPolicyNameEnum::NO,
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct ContainerState {
+    pub(crate) exists: bool,
+    pub(crate) running: bool,
+    pub(crate) health: Option<String>,
+}
+
+/// Connects to the Docker Engine at `endpoint` (the resolved context/`DOCKER_HOST`
+/// address), falling back to the local defaults when no endpoint was resolved.
+/// Transports bollard can't dial itself (e.g. an `ssh://` context) return an
+/// error so the caller falls back to shelling out to the CLI, which resolves
+/// those the same way `docker context use` would.
+pub(crate) fn connect(endpoint: Option<&str>) -> Result<Docker, String> {
+    match endpoint {
+        None => Docker::connect_with_local_defaults().map_err(|e| e.to_string()),
+        Some(host) if host.starts_with("unix://") || host.starts_with("npipe://") => {
+            Docker::connect_with_socket(host, 120, bollard::API_DEFAULT_VERSION).map_err(|e| e.to_string())
+        }
+        Some(host) if host.starts_with("tcp://") || host.starts_with("http://") => {
+            Docker::connect_with_http(host, 120, bollard::API_DEFAULT_VERSION).map_err(|e| e.to_string())
+        }
+        Some(other) => Err(format!("unsupported docker endpoint for native client: {other}")),
+    }
+}
+
+pub(crate) async fn pull_image(docker: &Docker, image: &str) -> Result<(), String> {
+    let options = bollard::image::CreateImageOptions {
+        from_image: image.to_string(),
+        ..Default::default()
+    };
+
+    let mut stream = docker.create_image(Some(options), None, None);
+    while let Some(result) = stream.next().await {
+        result.map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+async fn remove_if_exists(docker: &Docker, container_name: &str) -> Result<(), String> {
+    let options = RemoveContainerOptions {
+        force: true,
+        ..Default::default()
+    };
+    match docker.remove_container(container_name, Some(options)).await {
+        Ok(()) => Ok(()),
+        Err(bollard::errors::Error::DockerResponseServerError { status_code: 404, .. }) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+/// Creates (replacing any previous container of the same name) and starts the
+/// service described by `service`.
+pub(crate) async fn create_and_start(docker: &Docker, service: &Service) -> Result<(), String> {
+    remove_if_exists(docker, &service.container_name).await?;
+
+    let options = CreateContainerOptions {
+        name: service.container_name.clone(),
+        platform: None,
+    };
+    docker
+        .create_container(Some(options), service.to_bollard_config())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    docker
+        .start_container(&service.container_name, None::<StartContainerOptions<String>>)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+pub(crate) async fn stop(docker: &Docker, container_name: &str) -> Result<(), String> {
+    match docker
+        .stop_container(container_name, None::<StopContainerOptions>)
+        .await
+    {
+        Ok(()) => Ok(()),
+        Err(bollard::errors::Error::DockerResponseServerError { status_code: 404, .. }) => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+pub(crate) async fn remove(docker: &Docker, container_name: &str) -> Result<(), String> {
+    remove_if_exists(docker, container_name).await
+}
+
+pub(crate) async fn inspect(docker: &Docker, container_name: &str) -> ContainerState {
+    match docker.inspect_container(container_name, None).await {
+        Ok(inspect) => {
+            let running = inspect
+                .state
+                .as_ref()
+                .and_then(|s| s.running)
+                .unwrap_or(false);
+            let health = inspect
+                .state
+                .as_ref()
+                .and_then(|s| s.health.as_ref())
+                .and_then(|h| h.status)
+                .map(|status| format!("{status:?}").to_lowercase());
+            ContainerState {
+                exists: true,
+                running,
+                health,
+            }
+        }
+        Err(_) => ContainerState {
+            exists: false,
+            running: false,
+            health: None,
+        },
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct DanglingImage {
+    pub(crate) id: String,
+    pub(crate) size_bytes: u64,
+}
+
+/// Finds untagged (`<none>`) images that still carry a repo digest for `repo`,
+/// the layers a repeated `update_retreivr_and_restart` leaves behind each time
+/// it pulls a newer tag. Filtering on the repo digest (rather than a blanket
+/// dangling-image prune) keeps unrelated images untouched.
+pub(crate) async fn dangling_images_for_repo(
+    docker: &Docker,
+    repo: &str,
+) -> Result<Vec<DanglingImage>, String> {
+    let options = ListImagesOptions::<String> {
+        all: true,
+        ..Default::default()
+    };
+    let images = docker
+        .list_images(Some(options))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(images
+        .into_iter()
+        .filter(|image| is_dangling_image_for_repo(&image.repo_tags, &image.repo_digests, repo))
+        .map(|image| DanglingImage {
+            id: image.id,
+            size_bytes: image.size.max(0) as u64,
+        })
+        .collect())
+}
+
+/// True when `repo_tags`/`repo_digests` describe an untagged image whose repo
+/// digest still belongs to `repo`: no real tag left pointing at it, but it's
+/// traceable back to a pull of the repo being pruned.
+fn is_dangling_image_for_repo(repo_tags: &[String], repo_digests: &[String], repo: &str) -> bool {
+    let untagged = repo_tags.iter().all(|tag| tag == "<none>:<none>") || repo_tags.is_empty();
+    let belongs_to_repo = repo_digests
+        .iter()
+        .any(|digest| digest.split('@').next() == Some(repo));
+    untagged && belongs_to_repo
+}
+
+pub(crate) async fn remove_image(docker: &Docker, image_id: &str) -> Result<(), String> {
+    docker
+        .remove_image(image_id, None::<RemoveImageOptions>, None)
+        .await
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Tags an already-pulled image (e.g. one pulled from a mirror registry) under
+/// `repo_tag` so the rest of the launcher can keep referring to it by the
+/// configured image name.
+pub(crate) async fn tag_image(docker: &Docker, image: &str, repo_tag: &str) -> Result<(), String> {
+    let (repo, tag) = repo_tag.rsplit_once(':').unwrap_or((repo_tag, "latest"));
+    let options = bollard::image::TagImageOptions {
+        repo: repo.to_string(),
+        tag: tag.to_string(),
+    };
+    docker
+        .tag_image(image, Some(options))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Looks up the on-disk size of `image`, or `None` if it hasn't been pulled.
+pub(crate) async fn image_size(docker: &Docker, image: &str) -> Option<u64> {
+    docker
+        .inspect_image(image)
+        .await
+        .ok()
+        .and_then(|info| info.size)
+        .map(|size| size.max(0) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_dangling_image_for_repo;
+
+    #[test]
+    fn dangling_untagged_image_matching_repo_digest_is_kept() {
+        let repo_tags = vec!["<none>:<none>".to_string()];
+        let repo_digests = vec!["ghcr.io/sudostacks/retreivr@sha256:abc".to_string()];
+        assert!(is_dangling_image_for_repo(&repo_tags, &repo_digests, "ghcr.io/sudostacks/retreivr"));
+    }
+
+    #[test]
+    fn image_with_no_repo_tags_at_all_counts_as_untagged() {
+        let repo_digests = vec!["ghcr.io/sudostacks/retreivr@sha256:abc".to_string()];
+        assert!(is_dangling_image_for_repo(&[], &repo_digests, "ghcr.io/sudostacks/retreivr"));
+    }
+
+    #[test]
+    fn tagged_image_is_not_dangling_even_with_matching_digest() {
+        let repo_tags = vec!["ghcr.io/sudostacks/retreivr:latest".to_string()];
+        let repo_digests = vec!["ghcr.io/sudostacks/retreivr@sha256:abc".to_string()];
+        assert!(!is_dangling_image_for_repo(&repo_tags, &repo_digests, "ghcr.io/sudostacks/retreivr"));
+    }
+
+    #[test]
+    fn untagged_image_from_a_different_repo_is_not_matched() {
+        let repo_tags = vec!["<none>:<none>".to_string()];
+        let repo_digests = vec!["ghcr.io/other/image@sha256:abc".to_string()];
+        assert!(!is_dangling_image_for_repo(&repo_tags, &repo_digests, "ghcr.io/sudostacks/retreivr"));
+    }
+
+    #[test]
+    fn untagged_image_with_no_repo_digests_is_not_matched() {
+        let repo_tags = vec!["<none>:<none>".to_string()];
+        assert!(!is_dangling_image_for_repo(&repo_tags, &[], "ghcr.io/sudostacks/retreivr"));
+    }
+}
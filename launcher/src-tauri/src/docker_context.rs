@@ -0,0 +1,141 @@
+//! Docker context / remote-host awareness: users often point the CLI at a
+//! non-default engine (a remote host, a rootless daemon) via `docker context use`
+//! or `DOCKER_HOST`, and the launcher should follow along rather than silently
+//! assuming the local default engine.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+use crate::command_output;
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct DockerContextInfo {
+    pub(crate) name: String,
+    pub(crate) current: bool,
+    pub(crate) endpoint: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DockerConfigFile {
+    #[serde(rename = "currentContext")]
+    current_context: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContextLsEntry {
+    #[serde(rename = "Name")]
+    name: String,
+    #[serde(rename = "Current")]
+    current: bool,
+    #[serde(rename = "DockerEndpoint")]
+    docker_endpoint: Option<String>,
+}
+
+fn docker_config_path() -> Option<PathBuf> {
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    Some(PathBuf::from(home).join(".docker").join("config.json"))
+}
+
+/// Reads `currentContext` directly from `~/.docker/config.json`, the same file
+/// the Docker CLI itself consults.
+pub(crate) fn configured_current_context() -> Option<String> {
+    let path = docker_config_path()?;
+    let content = fs::read_to_string(path).ok()?;
+    let parsed: DockerConfigFile = serde_json::from_str(&content).ok()?;
+    parsed.current_context.filter(|c| !c.is_empty())
+}
+
+/// Enumerates contexts known to the CLI, which also reports each one's resolved
+/// endpoint (local socket, remote host, or rootless path).
+pub(crate) fn list_contexts(cli: &str) -> Result<Vec<DockerContextInfo>, String> {
+    let output = command_output({
+        let mut cmd = Command::new(cli);
+        cmd.args(["context", "ls", "--format", "{{json .}}"]);
+        cmd
+    })?;
+
+    output
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            serde_json::from_str::<ContextLsEntry>(line)
+                .map(|entry| DockerContextInfo {
+                    name: entry.name,
+                    current: entry.current,
+                    endpoint: entry.docker_endpoint,
+                })
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
+
+/// Resolves which context the launcher should use: an explicit `DOCKER_HOST`
+/// override wins, then the user's pinned context, then whatever `docker` itself
+/// currently has active.
+pub(crate) fn resolve_active_context(cli: &str, pinned: Option<&str>) -> Option<String> {
+    if let Ok(host) = env::var("DOCKER_HOST") {
+        if !host.is_empty() {
+            return Some(host);
+        }
+    }
+
+    pinned
+        .map(|name| name.to_string())
+        .or_else(configured_current_context)
+        .or_else(|| {
+            list_contexts(cli)
+                .ok()?
+                .into_iter()
+                .find(|c| c.current)
+                .map(|c| c.name)
+        })
+}
+
+/// Looks up the resolved endpoint (socket path / remote host) for `context_name`.
+pub(crate) fn endpoint_for(cli: &str, context_name: &str) -> Option<String> {
+    list_contexts(cli)
+        .ok()?
+        .into_iter()
+        .find(|c| c.name == context_name)
+        .and_then(|c| c.endpoint)
+}
+
+/// Resolves the endpoint the launcher should actually dial: `DOCKER_HOST` is
+/// already an endpoint and wins outright, otherwise the active context's name
+/// (pinned, configured, or CLI-reported) is looked up for its endpoint.
+pub(crate) fn resolve_endpoint(cli: &str, pinned: Option<&str>) -> Option<String> {
+    if let Ok(host) = env::var("DOCKER_HOST") {
+        if !host.is_empty() {
+            return Some(host);
+        }
+    }
+
+    let context_name = resolve_active_context(cli, pinned)?;
+    endpoint_for(cli, &context_name)
+}
+
+/// Applies a pinned Docker context to `cmd`: `--context name` when the target CLI
+/// actually supports it, or a `DOCKER_HOST` substitute (looked up from the
+/// `docker` context store, which owns context definitions regardless of which
+/// engine CLI ends up running) for runtimes that don't, like Podman/nerdctl.
+pub(crate) fn apply_context_flag(cmd: &mut Command, cli: &str, pinned: Option<&str>) {
+    let Some(name) = pinned else { return };
+
+    let is_docker_cli = Path::new(cli)
+        .file_stem()
+        .and_then(|stem| stem.to_str())
+        .is_some_and(|stem| stem.eq_ignore_ascii_case("docker"));
+
+    if is_docker_cli {
+        cmd.args(["--context", name]);
+        return;
+    }
+
+    if let Some(endpoint) = endpoint_for("docker", name) {
+        cmd.env("DOCKER_HOST", endpoint);
+    }
+}
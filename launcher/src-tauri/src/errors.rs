@@ -0,0 +1,80 @@
+//! Structured launcher error type. Tauri serializes a command's `Err` case back
+//! to the frontend, so `LauncherError` carries a machine-readable `kind` and a
+//! suggested `fix` alongside the human message, the same triage info
+//! `PreflightCheck.fix` already gives users for non-fatal checks.
+
+use serde::{Serialize, Serializer};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub(crate) enum LauncherError {
+    #[error("Docker engine is not available")]
+    DockerUnavailable,
+    #[error("docker compose failed: {stderr}")]
+    ComposeFailed { stderr: String },
+    #[error("port {0} is already in use")]
+    PortInUse(u16),
+    #[error("invalid settings: {0}")]
+    InvalidSettings(String),
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Other(String),
+}
+
+impl LauncherError {
+    fn kind(&self) -> &'static str {
+        match self {
+            LauncherError::DockerUnavailable => "docker_unavailable",
+            LauncherError::ComposeFailed { .. } => "compose_failed",
+            LauncherError::PortInUse(_) => "port_in_use",
+            LauncherError::InvalidSettings(_) => "invalid_settings",
+            LauncherError::Io(_) => "io_error",
+            LauncherError::Other(_) => "other",
+        }
+    }
+
+    fn fix(&self) -> String {
+        match self {
+            LauncherError::DockerUnavailable => {
+                "Start Docker Desktop (or your configured runtime) and retry.".to_string()
+            }
+            LauncherError::ComposeFailed { .. } => {
+                "Check the compose file and Docker permissions, then retry.".to_string()
+            }
+            LauncherError::PortInUse(port) => {
+                format!("Choose a host port other than {port} in configuration and save.")
+            }
+            LauncherError::InvalidSettings(_) => {
+                "Correct the highlighted configuration value and save again.".to_string()
+            }
+            LauncherError::Io(_) => {
+                "Check file system permissions for the launcher's app-data directory.".to_string()
+            }
+            LauncherError::Other(_) => "Retry the operation; if it persists, check the logs.".to_string(),
+        }
+    }
+}
+
+/// Serializes to `{ kind, message, fix }` instead of the derive's default
+/// enum-tagged shape, so the frontend can match on `kind` directly.
+impl Serialize for LauncherError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Payload {
+            kind: &'static str,
+            message: String,
+            fix: String,
+        }
+
+        Payload {
+            kind: self.kind(),
+            message: self.to_string(),
+            fix: self.fix(),
+        }
+        .serialize(serializer)
+    }
+}
@@ -1,3 +1,11 @@
+mod docker;
+mod docker_context;
+mod errors;
+mod runtime;
+mod sandbox;
+mod streaming;
+mod updater;
+
 use std::env;
 use std::ffi::OsStr;
 use std::fs;
@@ -5,16 +13,23 @@ use std::net::{SocketAddr, TcpListener, TcpStream};
 use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
-use std::time::Duration;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 use tauri::{AppHandle, Manager};
 
+use errors::LauncherError;
+
 const LAUNCHER_TAGS_API: &str = "https://api.github.com/repos/sudostacks/retreivr/tags?per_page=100";
 const LAUNCHER_RELEASES_URL: &str = "https://github.com/sudostacks/retreivr/releases";
 const DEFAULT_CONFIG_JSON: &str = include_str!("../../../config/config_sample.json");
+const DEFAULT_READY_TIMEOUT_MS: u64 = 60_000;
+const DEFAULT_READY_POLL_MS: u64 = 500;
+const MAX_READY_POLL_MS: u64 = 4_000;
+const DEFAULT_READINESS_MARKER: &str = "listening on";
 
-fn app_support_dir(app: &AppHandle) -> PathBuf {
+pub(crate) fn app_support_dir(app: &AppHandle) -> PathBuf {
     app.path()
         .app_data_dir()
         .expect("failed to resolve app data dir")
@@ -29,6 +44,19 @@ fn settings_path(app: &AppHandle) -> PathBuf {
     app_support_dir(app).join("launcher_settings.json")
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ReleaseChannel {
+    Stable,
+    Beta,
+}
+
+impl Default for ReleaseChannel {
+    fn default() -> Self {
+        ReleaseChannel::Stable
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 struct LauncherSettings {
     #[serde(default = "default_host_port")]
@@ -47,6 +75,18 @@ struct LauncherSettings {
     logs_dir: String,
     #[serde(default = "default_tokens_dir")]
     tokens_dir: String,
+    #[serde(default)]
+    preferred_runtime: Option<String>,
+    #[serde(default)]
+    release_channel: ReleaseChannel,
+    #[serde(default)]
+    docker_context: Option<String>,
+    #[serde(default)]
+    clean_up_old_images: bool,
+    /// Fallback registry mirrors tried, in order, when a pull against `image`'s
+    /// own registry fails (e.g. a pull-through cache for a flaky upstream).
+    #[serde(default)]
+    image_mirrors: Vec<String>,
 }
 
 fn default_host_port() -> u16 {
@@ -92,10 +132,21 @@ impl Default for LauncherSettings {
             downloads_dir: default_downloads_dir(),
             logs_dir: default_logs_dir(),
             tokens_dir: default_tokens_dir(),
+            preferred_runtime: None,
+            release_channel: ReleaseChannel::default(),
+            docker_context: None,
+            clean_up_old_images: false,
+            image_mirrors: Vec::new(),
         }
     }
 }
 
+/// Cached `LauncherSettings`, managed as Tauri state so commands read the
+/// in-memory copy instead of re-parsing `launcher_settings.json` on every
+/// invocation. [`load_settings`] reads it; [`save_settings_to_disk`] updates
+/// both the cache and disk together so the two never drift apart.
+struct SettingsState(Mutex<LauncherSettings>);
+
 #[derive(Debug, Serialize)]
 struct DockerDiagnostics {
     docker_installed: bool,
@@ -107,9 +158,47 @@ struct DockerDiagnostics {
     web_url: String,
     compose_path: String,
     runtime_dir: String,
+    detected_runtime: Option<String>,
+    active_context: Option<String>,
+    resolved_endpoint: Option<String>,
     last_error: Option<String>,
 }
 
+#[derive(Debug, Serialize)]
+struct DockerContextsReport {
+    contexts: Vec<docker_context::DockerContextInfo>,
+    active: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ReadinessState {
+    Healthy,
+    TimedOut,
+    Exited,
+}
+
+#[derive(Debug, Serialize)]
+struct ReadinessReport {
+    state: ReadinessState,
+    elapsed_ms: u64,
+    detail: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ImagePruneReport {
+    removed_image_ids: Vec<String>,
+    space_reclaimed_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct DiskUsageReport {
+    retreivr_image_bytes: Option<u64>,
+    retreivr_data_bytes: u64,
+    dangling_image_count: usize,
+    reclaimable_bytes: u64,
+}
+
 #[derive(Debug, Serialize)]
 struct InstallGuidance {
     os: String,
@@ -172,9 +261,9 @@ struct GithubTagResponse {
 }
 
 #[derive(Debug)]
-struct LauncherTagInfo {
-    tag_name: String,
-    html_url: String,
+pub(crate) struct LauncherTagInfo {
+    pub(crate) tag_name: String,
+    pub(crate) html_url: String,
 }
 
 fn web_url(settings: &LauncherSettings) -> String {
@@ -213,40 +302,61 @@ fn normalize_settings(settings: &LauncherSettings) -> LauncherSettings {
     out
 }
 
-fn load_settings(app: &AppHandle) -> LauncherSettings {
+/// Reads settings from the managed in-memory cache rather than the disk,
+/// populated at startup (and kept current by [`save_settings_to_disk`]).
+pub(crate) fn load_settings(app: &AppHandle) -> LauncherSettings {
+    app.state::<SettingsState>()
+        .0
+        .lock()
+        .expect("settings cache poisoned")
+        .clone()
+}
+
+/// Loads settings for the managed state's initial population at startup: via
+/// `confy` from `launcher_settings.json`, or `LauncherSettings::default()` if the
+/// file doesn't exist yet (nothing is written then) or fails to parse.
+fn load_settings_from_disk(app: &AppHandle) -> LauncherSettings {
     let path = settings_path(app);
-    let content = match fs::read_to_string(path) {
-        Ok(value) => value,
-        Err(_) => return LauncherSettings::default(),
+    // `confy::load_path` would write a default file when none exists, which would
+    // make the onboarding "Configuration saved" check pass before any save.
+    let loaded = if path.exists() {
+        confy::load_path(&path).unwrap_or_default()
+    } else {
+        LauncherSettings::default()
     };
-
-    let parsed = serde_json::from_str(&content).unwrap_or_else(|_| LauncherSettings::default());
-    normalize_settings(&parsed)
+    normalize_settings(&loaded)
 }
 
-fn save_settings_to_disk(app: &AppHandle, settings: &LauncherSettings) -> Result<(), String> {
+fn save_settings_to_disk(app: &AppHandle, settings: &LauncherSettings) -> Result<(), LauncherError> {
     let dir = app_support_dir(app);
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    fs::create_dir_all(&dir)?;
     let normalized = normalize_settings(settings);
-    let payload = serde_json::to_string_pretty(&normalized).map_err(|e| e.to_string())?;
-    fs::write(settings_path(app), payload).map_err(|e| e.to_string())
+    confy::store_path(settings_path(app), &normalized).map_err(|e| LauncherError::Other(e.to_string()))?;
+    *app.state::<SettingsState>().0.lock().expect("settings cache poisoned") = normalized;
+    Ok(())
 }
 
-fn validate_settings(settings: &LauncherSettings) -> Result<(), String> {
+fn validate_settings(settings: &LauncherSettings) -> Result<(), LauncherError> {
     if settings.host_port == 0 {
-        return Err("host_port must be between 1 and 65535".to_string());
+        return Err(LauncherError::InvalidSettings(
+            "host_port must be between 1 and 65535".to_string(),
+        ));
     }
 
     if settings.image.trim().is_empty() {
-        return Err("image cannot be empty".to_string());
+        return Err(LauncherError::InvalidSettings("image cannot be empty".to_string()));
     }
 
     if settings.image.chars().any(|c| c.is_ascii_uppercase()) {
-        return Err("image must be lowercase (Docker image refs are case-sensitive)".to_string());
+        return Err(LauncherError::InvalidSettings(
+            "image must be lowercase (Docker image refs are case-sensitive)".to_string(),
+        ));
     }
 
     if settings.container_name.trim().is_empty() {
-        return Err("container_name cannot be empty".to_string());
+        return Err(LauncherError::InvalidSettings(
+            "container_name cannot be empty".to_string(),
+        ));
     }
 
     if !settings
@@ -254,7 +364,9 @@ fn validate_settings(settings: &LauncherSettings) -> Result<(), String> {
         .chars()
         .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_' || c == '.')
     {
-        return Err("container_name may only contain letters, numbers, '-', '_' and '.'".to_string());
+        return Err(LauncherError::InvalidSettings(
+            "container_name may only contain letters, numbers, '-', '_' and '.'".to_string(),
+        ));
     }
 
     for (name, value) in [
@@ -265,7 +377,7 @@ fn validate_settings(settings: &LauncherSettings) -> Result<(), String> {
         ("tokens_dir", settings.tokens_dir.as_str()),
     ] {
         if value.trim().is_empty() {
-            return Err(format!("{name} cannot be empty"));
+            return Err(LauncherError::InvalidSettings(format!("{name} cannot be empty")));
         }
     }
 
@@ -282,54 +394,43 @@ fn resolve_mount_source(app: &AppHandle, configured: &str) -> PathBuf {
     app_support_dir(app).join(cleaned)
 }
 
-fn yaml_quote_path(path: &Path) -> String {
-    path.to_string_lossy().replace('\\', "\\\\")
-}
-
-fn render_compose(app: &AppHandle, settings: &LauncherSettings) -> String {
-    let config_source = resolve_mount_source(app, &settings.config_dir);
-    let data_source = resolve_mount_source(app, &settings.data_dir);
-    let downloads_source = resolve_mount_source(app, &settings.downloads_dir);
-    let logs_source = resolve_mount_source(app, &settings.logs_dir);
-    let tokens_source = resolve_mount_source(app, &settings.tokens_dir);
-
-    format!(
-        r#"services:
-  retreivr:
-    image: {image}
-    container_name: {container_name}
-    restart: unless-stopped
-    ports:
-      - "{host_port}:8000"
-    volumes:
-      - type: bind
-        source: "{config_source}"
-        target: "/config"
-      - type: bind
-        source: "{data_source}"
-        target: "/data"
-      - type: bind
-        source: "{downloads_source}"
-        target: "/downloads"
-      - type: bind
-        source: "{logs_source}"
-        target: "/logs"
-      - type: bind
-        source: "{tokens_source}"
-        target: "/tokens"
-"#,
-        image = settings.image,
-        container_name = settings.container_name,
-        host_port = settings.host_port,
-        config_source = yaml_quote_path(&config_source),
-        data_source = yaml_quote_path(&data_source),
-        downloads_source = yaml_quote_path(&downloads_source),
-        logs_source = yaml_quote_path(&logs_source),
-        tokens_source = yaml_quote_path(&tokens_source)
-    )
+/// Builds the typed compose model for the configured service, used both to render
+/// `compose.yaml` and to drive the native bollard container-create path. `source`
+/// is kept as a raw, unescaped path; [`docker::Volume`] itself handles doubling
+/// backslashes when serializing to YAML.
+fn compose_model(app: &AppHandle, settings: &LauncherSettings) -> docker::DockerCompose {
+    let mount = |configured: &str, target: &str| docker::Volume {
+        kind: "bind".to_string(),
+        source: resolve_mount_source(app, configured).to_string_lossy().to_string(),
+        target: target.to_string(),
+    };
+
+    let service = docker::Service {
+        image: settings.image.clone(),
+        container_name: settings.container_name.clone(),
+        restart: "unless-stopped".to_string(),
+        ports: vec![format!("{}:8000", settings.host_port)],
+        volumes: vec![
+            mount(&settings.config_dir, "/config"),
+            mount(&settings.data_dir, "/data"),
+            mount(&settings.downloads_dir, "/downloads"),
+            mount(&settings.logs_dir, "/logs"),
+            mount(&settings.tokens_dir, "/tokens"),
+        ],
+    };
+
+    docker::DockerCompose {
+        services: [("retreivr".to_string(), service)].into_iter().collect(),
+    }
+}
+
+fn render_compose(app: &AppHandle, settings: &LauncherSettings) -> Result<String, LauncherError> {
+    compose_model(app, settings)
+        .to_yaml()
+        .map_err(LauncherError::Other)
 }
 
-fn ensure_runtime_dirs(app: &AppHandle, settings: &LauncherSettings) -> Result<(), String> {
+fn ensure_runtime_dirs(app: &AppHandle, settings: &LauncherSettings) -> Result<(), LauncherError> {
     let config_dir = resolve_mount_source(app, &settings.config_dir);
     let data_dir = resolve_mount_source(app, &settings.data_dir);
     let downloads_dir = resolve_mount_source(app, &settings.downloads_dir);
@@ -337,16 +438,37 @@ fn ensure_runtime_dirs(app: &AppHandle, settings: &LauncherSettings) -> Result<(
     let tokens_dir = resolve_mount_source(app, &settings.tokens_dir);
 
     for dir in [&config_dir, &data_dir, &downloads_dir, &logs_dir, &tokens_dir] {
-        fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+        fs::create_dir_all(dir)?;
     }
 
     let config_json_path = config_dir.join("config.json");
     if !config_json_path.exists() {
-        fs::write(&config_json_path, DEFAULT_CONFIG_JSON).map_err(|e| e.to_string())?;
+        fs::write(&config_json_path, DEFAULT_CONFIG_JSON)?;
     }
     Ok(())
 }
 
+/// Recursively sums file sizes under `path`, skipping entries that error out
+/// (e.g. a dangling symlink) rather than failing the whole report.
+fn dir_size_bytes(path: &Path) -> u64 {
+    let Ok(entries) = fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| {
+            let Ok(metadata) = entry.metadata() else {
+                return 0;
+            };
+            if metadata.is_dir() {
+                dir_size_bytes(&entry.path())
+            } else {
+                metadata.len()
+            }
+        })
+        .sum()
+}
+
 fn service_reachable(port: u16) -> bool {
     let addr = SocketAddr::from(([127, 0, 0, 1], port));
     TcpStream::connect_timeout(&addr, Duration::from_millis(700)).is_ok()
@@ -356,14 +478,18 @@ fn host_port_available(port: u16) -> bool {
     TcpListener::bind(("127.0.0.1", port)).is_ok()
 }
 
-fn with_runtime_path(cmd: &mut Command) {
+const RUNTIME_CLI_NAMES: [&str; 3] = ["docker", "podman", "nerdctl"];
+
+pub(crate) fn with_runtime_path(cmd: &mut Command) {
     let program = cmd.get_program();
-    let is_docker = program == OsStr::new("docker")
-        || Path::new(program)
-            .file_name()
-            .is_some_and(|name| name == OsStr::new("docker"));
+    let program_name = Path::new(program).file_name().unwrap_or(program);
+    let is_runtime_cli = RUNTIME_CLI_NAMES
+        .iter()
+        .any(|name| program_name == OsStr::new(name));
+
+    sandbox::normalize_host_env(cmd);
 
-    if !is_docker {
+    if !is_runtime_cli {
         return;
     }
 
@@ -392,7 +518,7 @@ fn command_success(mut cmd: Command) -> bool {
     cmd.output().map(|o| o.status.success()).unwrap_or(false)
 }
 
-fn command_output(mut cmd: Command) -> Result<String, String> {
+pub(crate) fn command_output(mut cmd: Command) -> Result<String, String> {
     with_runtime_path(&mut cmd);
     let output = cmd.output().map_err(|e| e.to_string())?;
     if output.status.success() {
@@ -436,9 +562,93 @@ fn diagnostics_failure_message(
     None
 }
 
+/// Maps the configured image to the tag published for `channel` (`:latest` for
+/// stable, `:edge` for beta), preserving whatever repository the user configured.
+fn image_for_channel(image: &str, channel: ReleaseChannel) -> String {
+    let tag = match channel {
+        ReleaseChannel::Stable => "latest",
+        ReleaseChannel::Beta => "edge",
+    };
+    match image.rsplit_once(':') {
+        Some((repo, _existing_tag)) => format!("{repo}:{tag}"),
+        None => format!("{image}:{tag}"),
+    }
+}
+
+/// Strips any tag off `image`, leaving the bare repository (e.g.
+/// `ghcr.io/sudostacks/retreivr:latest` -> `ghcr.io/sudostacks/retreivr`), so
+/// pruning can be scoped to images that belong to the configured repo.
+fn image_repo(image: &str) -> &str {
+    image.rsplit_once(':').map(|(repo, _tag)| repo).unwrap_or(image)
+}
+
+/// Builds the ordered list of image references to try for a pull: `image`
+/// itself first, then the same repo path re-hosted under each configured
+/// mirror registry, so a flaky primary registry falls back to a pull-through
+/// cache instead of failing the update outright.
+fn mirrored_image_candidates(image: &str, mirrors: &[String]) -> Vec<String> {
+    let mut candidates = vec![image.to_string()];
+    if let Some((_registry, path_and_tag)) = image.split_once('/') {
+        for mirror in mirrors {
+            candidates.push(format!("{}/{path_and_tag}", mirror.trim_end_matches('/')));
+        }
+    }
+    candidates
+}
+
+/// Resolves the CLI binary to drive: the configured/detected container runtime if
+/// one was found, otherwise the plain `docker` binary on PATH.
+fn runtime_cli(settings: &LauncherSettings) -> String {
+    runtime_cli_and_kind(settings).0
+}
+
+/// Resolves both the detected CLI path and its [`runtime::RuntimeKind`], so
+/// callers that invoke `compose` can use the kind's own invocation style
+/// instead of assuming every runtime speaks plain Docker Compose syntax.
+fn runtime_cli_and_kind(settings: &LauncherSettings) -> (String, runtime::RuntimeKind) {
+    match runtime::pick_runtime(settings.preferred_runtime.as_deref()) {
+        Some(runtime) => (runtime.cli_path, runtime.kind),
+        None => ("docker".to_string(), runtime::RuntimeKind::Docker),
+    }
+}
+
+/// Builds a `Command` for `cli` pre-seeded with the pinned Docker context (if
+/// any) and `kind`'s compose subcommand prefix (e.g. `compose` vs a
+/// runtime-specific invocation), ready for the caller to append the rest of
+/// the compose arguments.
+fn compose_command(cli: &str, kind: runtime::RuntimeKind, pinned: Option<&str>) -> Command {
+    let mut cmd = Command::new(cli);
+    docker_context::apply_context_flag(&mut cmd, cli, pinned);
+    cmd.args(kind.compose_subcommand());
+    cmd
+}
+
+/// Resolves the endpoint the native bollard client should dial for the
+/// configured/pinned context, or `None` to fall back to local defaults.
+fn resolved_docker_endpoint(settings: &LauncherSettings) -> Option<String> {
+    docker_context::resolve_endpoint(&runtime_cli(settings), settings.docker_context.as_deref())
+}
+
+/// Checks whether the configured engine actually responds, so commands can
+/// report `LauncherError::DockerUnavailable` up front instead of a confusing
+/// compose failure further down.
+fn engine_available(settings: &LauncherSettings) -> bool {
+    let cli = runtime_cli(settings);
+    command_success({
+        let mut cmd = Command::new(&cli);
+        docker_context::apply_context_flag(&mut cmd, &cli, settings.docker_context.as_deref());
+        cmd.arg("info");
+        cmd
+    })
+}
+
+/// Runs `compose <args>` (e.g. `["version"]`, `["logs", "--tail", "50"]`) through
+/// the detected runtime's own compose invocation style.
 fn run_compose_with_output(app: &AppHandle, args: &[&str]) -> Result<String, String> {
+    let settings = load_settings(app);
+    let (cli, kind) = runtime_cli_and_kind(&settings);
     command_output({
-        let mut cmd = Command::new("docker");
+        let mut cmd = compose_command(&cli, kind, settings.docker_context.as_deref());
         cmd.args(args).current_dir(app_support_dir(app));
         cmd
     })
@@ -466,6 +676,8 @@ fn open_in_file_manager(path: &Path) -> Result<(), String> {
         c
     };
 
+    sandbox::normalize_host_env(&mut cmd);
+
     cmd.status().map_err(|e| e.to_string()).and_then(|s| {
         if s.success() {
             Ok(())
@@ -516,10 +728,10 @@ fn pick_folder_via_system() -> Result<Option<String>, String> {
 
     #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
     {
-        let output = Command::new("zenity")
-            .args(["--file-selection", "--directory"])
-            .output()
-            .map_err(|e| e.to_string())?;
+        let mut cmd = Command::new("zenity");
+        cmd.args(["--file-selection", "--directory"]);
+        sandbox::normalize_host_env(&mut cmd);
+        let output = cmd.output().map_err(|e| e.to_string())?;
         if !output.status.success() {
             return Ok(None);
         }
@@ -528,7 +740,7 @@ fn pick_folder_via_system() -> Result<Option<String>, String> {
     }
 }
 
-fn normalize_release_tag(tag: &str) -> String {
+pub(crate) fn normalize_release_tag(tag: &str) -> String {
     tag.trim()
         .trim_start_matches("launcher-v")
         .trim_start_matches('v')
@@ -544,9 +756,44 @@ fn parse_version_triplet(value: &str) -> Option<(u64, u64, u64)> {
     Some((major, minor, patch))
 }
 
-fn image_id_for(image: &str) -> Option<String> {
+/// Companion to [`parse_version_triplet`] that also understands a trailing
+/// `-<identifier>.<n>` prerelease suffix (e.g. `1.3.0-beta.2`), returning the
+/// semver core plus the prerelease identifier/number when present.
+fn parse_version_with_prerelease(value: &str) -> Option<((u64, u64, u64), Option<(String, u64)>)> {
+    let clean = normalize_release_tag(value);
+    let (core, prerelease) = match clean.split_once('-') {
+        Some((core, pre)) => (core, Some(pre)),
+        None => (clean.as_str(), None),
+    };
+
+    let mut parts = core.split('.');
+    let major = parts.next()?.parse::<u64>().ok()?;
+    let minor = parts.next()?.parse::<u64>().ok()?;
+    let patch = parts.next()?.parse::<u64>().ok()?;
+
+    let prerelease = prerelease.map(|pre| {
+        let (identifier, number) = pre.split_once('.').unwrap_or((pre, "0"));
+        (identifier.to_string(), number.parse::<u64>().unwrap_or(0))
+    });
+
+    Some(((major, minor, patch), prerelease))
+}
+
+/// Orders parsed versions so prereleases sort below the matching release
+/// (`1.3.0-beta.2` < `1.3.0`), and prereleases of the same core version sort by
+/// identifier then number.
+fn version_sort_key(parsed: &((u64, u64, u64), Option<(String, u64)>)) -> (u64, u64, u64, u8, String, u64) {
+    let (core, prerelease) = parsed;
+    match prerelease {
+        None => (core.0, core.1, core.2, 1, String::new(), 0),
+        Some((identifier, number)) => (core.0, core.1, core.2, 0, identifier.clone(), *number),
+    }
+}
+
+fn image_id_for(cli: &str, image: &str, pinned_context: Option<&str>) -> Option<String> {
     command_output({
-        let mut cmd = Command::new("docker");
+        let mut cmd = Command::new(cli);
+        docker_context::apply_context_flag(&mut cmd, cli, pinned_context);
         cmd.args(["image", "inspect", image, "--format", "{{.Id}}"]);
         cmd
     })
@@ -558,11 +805,13 @@ fn parse_tags_from_json(payload: &str) -> Result<Vec<GithubTagResponse>, String>
     serde_json::from_str::<Vec<GithubTagResponse>>(payload).map_err(|e| e.to_string())
 }
 
-fn pick_latest_launcher_tag(tags: &[GithubTagResponse]) -> Option<String> {
-    let mut parsed: Vec<((u64, u64, u64), String)> = tags
+fn pick_latest_launcher_tag(tags: &[GithubTagResponse], channel: ReleaseChannel) -> Option<String> {
+    let mut parsed: Vec<((u64, u64, u64, u8, String, u64), String)> = tags
         .iter()
         .filter(|tag| tag.name.starts_with("launcher-v"))
-        .filter_map(|tag| parse_version_triplet(&tag.name).map(|sem| (sem, tag.name.clone())))
+        .filter_map(|tag| parse_version_with_prerelease(&tag.name).map(|sem| (sem, tag.name.clone())))
+        .filter(|(sem, _)| channel == ReleaseChannel::Beta || sem.1.is_none())
+        .map(|(sem, name)| (version_sort_key(&sem), name))
         .collect();
 
     if parsed.is_empty() {
@@ -573,7 +822,9 @@ fn pick_latest_launcher_tag(tags: &[GithubTagResponse]) -> Option<String> {
     parsed.last().map(|value| value.1.clone())
 }
 
-fn fetch_latest_launcher_release() -> Result<Option<LauncherTagInfo>, String> {
+pub(crate) fn fetch_latest_launcher_release(
+    channel: ReleaseChannel,
+) -> Result<Option<LauncherTagInfo>, String> {
     let curl_result = command_output({
         let mut cmd = Command::new("curl");
         cmd.args([
@@ -588,7 +839,7 @@ fn fetch_latest_launcher_release() -> Result<Option<LauncherTagInfo>, String> {
 
     if curl_result.is_ok() {
         let tags = curl_result?;
-        let latest = pick_latest_launcher_tag(&tags).map(|tag_name| LauncherTagInfo {
+        let latest = pick_latest_launcher_tag(&tags, channel).map(|tag_name| LauncherTagInfo {
             html_url: format!("https://github.com/sudostacks/retreivr/releases/tag/{tag_name}"),
             tag_name,
         });
@@ -610,7 +861,7 @@ fn fetch_latest_launcher_release() -> Result<Option<LauncherTagInfo>, String> {
 
         if pwsh_result.is_ok() {
             let tags = pwsh_result?;
-            let latest = pick_latest_launcher_tag(&tags).map(|tag_name| LauncherTagInfo {
+            let latest = pick_latest_launcher_tag(&tags, channel).map(|tag_name| LauncherTagInfo {
                 html_url: format!("https://github.com/sudostacks/retreivr/releases/tag/{tag_name}"),
                 tag_name,
             });
@@ -631,7 +882,7 @@ fn fetch_latest_launcher_release() -> Result<Option<LauncherTagInfo>, String> {
 
     if wget_result.is_ok() {
         let tags = wget_result?;
-        let latest = pick_latest_launcher_tag(&tags).map(|tag_name| LauncherTagInfo {
+        let latest = pick_latest_launcher_tag(&tags, channel).map(|tag_name| LauncherTagInfo {
             html_url: format!("https://github.com/sudostacks/retreivr/releases/tag/{tag_name}"),
             tag_name,
         });
@@ -685,14 +936,15 @@ fn install_guidance() -> InstallGuidance {
 #[tauri::command]
 fn launcher_version_info(app: AppHandle) -> LauncherVersionInfo {
     let current_version = app.package_info().version.to_string();
-    let release = fetch_latest_launcher_release();
+    let channel = load_settings(&app).release_channel;
+    let release = fetch_latest_launcher_release(channel);
 
     match release {
         Ok(Some(latest)) => {
             let latest_clean = normalize_release_tag(&latest.tag_name);
             let update_available = match (
                 parse_version_triplet(&current_version),
-                parse_version_triplet(&latest_clean),
+                parse_version_with_prerelease(&latest_clean).map(|(core, _)| core),
             ) {
                 (Some(current), Some(remote)) => remote > current,
                 _ => latest_clean != normalize_release_tag(&current_version),
@@ -725,7 +977,10 @@ fn launcher_version_info(app: AppHandle) -> LauncherVersionInfo {
 
 #[cfg(test)]
 mod tests {
-    use super::{normalize_release_tag, parse_version_triplet};
+    use super::{
+        image_repo, mirrored_image_candidates, normalize_release_tag, parse_version_triplet,
+        parse_version_with_prerelease, version_sort_key,
+    };
 
     #[test]
     fn normalize_release_tag_handles_prefixes() {
@@ -734,22 +989,145 @@ mod tests {
         assert_eq!(normalize_release_tag("1.2.3"), "1.2.3");
     }
 
+    #[test]
+    fn image_repo_strips_trailing_tag_only() {
+        assert_eq!(image_repo("ghcr.io/sudostacks/retreivr:latest"), "ghcr.io/sudostacks/retreivr");
+        assert_eq!(image_repo("ghcr.io/sudostacks/retreivr"), "ghcr.io/sudostacks/retreivr");
+        assert_eq!(image_repo("retreivr:1.2.3"), "retreivr");
+    }
+
+    #[test]
+    fn mirrored_image_candidates_includes_original_first() {
+        let mirrors = vec!["mirror.example.com".to_string()];
+        let candidates = mirrored_image_candidates("ghcr.io/sudostacks/retreivr:latest", &mirrors);
+        assert_eq!(
+            candidates,
+            vec![
+                "ghcr.io/sudostacks/retreivr:latest".to_string(),
+                "mirror.example.com/sudostacks/retreivr:latest".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn mirrored_image_candidates_trims_trailing_slash_on_mirror() {
+        let mirrors = vec!["mirror.example.com/".to_string()];
+        let candidates = mirrored_image_candidates("ghcr.io/sudostacks/retreivr:latest", &mirrors);
+        assert_eq!(candidates[1], "mirror.example.com/sudostacks/retreivr:latest");
+    }
+
+    #[test]
+    fn mirrored_image_candidates_skips_mirrors_for_unqualified_image() {
+        let mirrors = vec!["mirror.example.com".to_string()];
+        let candidates = mirrored_image_candidates("retreivr:latest", &mirrors);
+        assert_eq!(candidates, vec!["retreivr:latest".to_string()]);
+    }
+
+    #[test]
+    fn mirrored_image_candidates_empty_mirrors_returns_original_only() {
+        let candidates = mirrored_image_candidates("ghcr.io/sudostacks/retreivr:latest", &[]);
+        assert_eq!(candidates, vec!["ghcr.io/sudostacks/retreivr:latest".to_string()]);
+    }
+
     #[test]
     fn parse_version_triplet_parses_semver_core() {
         assert_eq!(parse_version_triplet("launcher-v2.10.4"), Some((2, 10, 4)));
         assert_eq!(parse_version_triplet("v0.9.6"), Some((0, 9, 6)));
         assert_eq!(parse_version_triplet("bad"), None);
     }
+
+    #[test]
+    fn parse_version_with_prerelease_parses_beta_suffix() {
+        assert_eq!(
+            parse_version_with_prerelease("launcher-v1.3.0-beta.2"),
+            Some(((1, 3, 0), Some(("beta".to_string(), 2))))
+        );
+        assert_eq!(
+            parse_version_with_prerelease("launcher-v1.3.0"),
+            Some(((1, 3, 0), None))
+        );
+    }
+
+    #[test]
+    fn version_sort_key_orders_prerelease_below_release() {
+        let release = parse_version_with_prerelease("1.3.0").unwrap();
+        let beta = parse_version_with_prerelease("1.3.0-beta.2").unwrap();
+        assert!(version_sort_key(&beta) < version_sort_key(&release));
+    }
+}
+
+#[tauri::command]
+async fn download_and_apply_launcher_update(app: AppHandle) -> Result<String, String> {
+    updater::download_and_apply_update(&app).await
+}
+
+#[tauri::command]
+fn stream_pull_retreivr_image(app: AppHandle, stream_id: String) -> Result<(), String> {
+    let settings = load_settings(&app);
+    let image = image_for_channel(&settings.image, settings.release_channel);
+    let cli = runtime_cli(&settings);
+    let mut cmd = Command::new(&cli);
+    docker_context::apply_context_flag(&mut cmd, &cli, settings.docker_context.as_deref());
+    cmd.args(["pull", &image]);
+    streaming::spawn_streamed(&app, stream_id.clone(), "retreivr://pull-progress", cmd)?;
+    streaming::await_completion(&app, stream_id);
+    Ok(())
+}
+
+#[tauri::command]
+fn stream_compose_up(app: AppHandle, stream_id: String) -> Result<(), String> {
+    let settings = load_settings(&app);
+    let (cli, kind) = runtime_cli_and_kind(&settings);
+    let mut cmd = compose_command(&cli, kind, settings.docker_context.as_deref());
+    cmd.args(["up", "-d"]).current_dir(app_support_dir(&app));
+    streaming::spawn_streamed(&app, stream_id.clone(), "retreivr://compose-progress", cmd)?;
+    streaming::await_completion(&app, stream_id);
+    Ok(())
+}
+
+#[tauri::command]
+fn stream_compose_down(app: AppHandle, stream_id: String) -> Result<(), String> {
+    let settings = load_settings(&app);
+    let (cli, kind) = runtime_cli_and_kind(&settings);
+    let mut cmd = compose_command(&cli, kind, settings.docker_context.as_deref());
+    cmd.args(["down"]).current_dir(app_support_dir(&app));
+    streaming::spawn_streamed(&app, stream_id.clone(), "retreivr://compose-progress", cmd)?;
+    streaming::await_completion(&app, stream_id);
+    Ok(())
+}
+
+#[tauri::command]
+fn cancel_stream(app: AppHandle, stream_id: String) -> Result<(), String> {
+    streaming::cancel_stream(&app, &stream_id)
+}
+
+#[tauri::command]
+fn start_log_stream(app: AppHandle, stream_id: String, lines: Option<u32>) -> Result<(), String> {
+    let settings = load_settings(&app);
+    let tail = lines.unwrap_or(200).clamp(20, 2000).to_string();
+    let (cli, kind) = runtime_cli_and_kind(&settings);
+    let mut cmd = compose_command(&cli, kind, settings.docker_context.as_deref());
+    cmd.args(["logs", "-f", "--tail", &tail, "retreivr"])
+        .current_dir(app_support_dir(&app));
+    streaming::spawn_log_stream(&app, stream_id, cmd)
+}
+
+#[tauri::command]
+fn stop_log_stream(app: AppHandle, stream_id: String) -> Result<(), String> {
+    streaming::cancel_stream(&app, &stream_id)
 }
 
 #[tauri::command]
 fn check_retreivr_image_update(app: AppHandle) -> ImageUpdateStatus {
     let settings = load_settings(&app);
-    let image = settings.image;
-    let local_image_id = image_id_for(&image);
+    let cli = runtime_cli(&settings);
+    let pinned = settings.docker_context.as_deref();
+    let image = image_for_channel(&settings.image, settings.release_channel);
+    let local_image_id = image_id_for(&cli, &image, pinned);
 
     let pull_result = command_output({
-        let mut cmd = Command::new("docker");
+        let mut cmd = Command::new(&cli);
+        docker_context::apply_context_flag(&mut cmd, &cli, pinned);
         cmd.args(["pull", &image]);
         cmd
     });
@@ -764,7 +1142,7 @@ fn check_retreivr_image_update(app: AppHandle) -> ImageUpdateStatus {
         };
     }
 
-    let remote_image_id = image_id_for(&image);
+    let remote_image_id = image_id_for(&cli, &image, pinned);
     let update_available = match (&local_image_id, &remote_image_id) {
         (Some(local), Some(remote)) => local != remote,
         (None, Some(_)) => false,
@@ -781,40 +1159,169 @@ fn check_retreivr_image_update(app: AppHandle) -> ImageUpdateStatus {
 }
 
 #[tauri::command]
-fn update_retreivr_and_restart(app: AppHandle) -> Result<String, String> {
+async fn update_retreivr_and_restart(app: AppHandle) -> Result<String, LauncherError> {
     let settings = load_settings(&app);
     validate_settings(&settings)?;
-    fs::create_dir_all(app_support_dir(&app)).map_err(|e| e.to_string())?;
+    if !engine_available(&settings) {
+        return Err(LauncherError::DockerUnavailable);
+    }
+    fs::create_dir_all(app_support_dir(&app))?;
     ensure_runtime_dirs(&app, &settings)?;
-    fs::write(compose_path(&app), render_compose(&app, &settings)).map_err(|e| e.to_string())?;
-
-    let image = settings.image;
-    let before = image_id_for(&image);
-    command_output({
-        let mut cmd = Command::new("docker");
-        cmd.args(["pull", &image]);
-        cmd
-    })?;
-    let after = image_id_for(&image);
+    let model = compose_model(&app, &settings);
+    fs::write(compose_path(&app), model.to_yaml().map_err(LauncherError::Other)?)?;
+
+    let (cli, kind) = runtime_cli_and_kind(&settings);
+    let pinned = settings.docker_context.as_deref();
+    let image = image_for_channel(&settings.image, settings.release_channel);
+    let before = image_id_for(&cli, &image, pinned);
+    let candidates = mirrored_image_candidates(&image, &settings.image_mirrors);
+    let mut pull_error = None;
+    for candidate in &candidates {
+        let pulled = command_output({
+            let mut cmd = Command::new(&cli);
+            docker_context::apply_context_flag(&mut cmd, &cli, pinned);
+            cmd.args(["pull", candidate]);
+            cmd
+        });
+        match pulled {
+            Ok(_) if candidate == &image => {
+                pull_error = None;
+                break;
+            }
+            // A mirror was pulled under its own reference; tag it as `image` so
+            // the rest of the flow (id lookup, compose) sees the expected name.
+            Ok(_) => {
+                pull_error = command_output({
+                    let mut cmd = Command::new(&cli);
+                    docker_context::apply_context_flag(&mut cmd, &cli, pinned);
+                    cmd.args(["tag", candidate, &image]);
+                    cmd
+                })
+                .err();
+                break;
+            }
+            Err(stderr) => pull_error = Some(stderr),
+        }
+    }
+    if let Some(stderr) = pull_error {
+        return Err(LauncherError::ComposeFailed { stderr });
+    }
+    let after = image_id_for(&cli, &image, pinned);
     let updated = match (&before, &after) {
         (Some(lhs), Some(rhs)) => lhs != rhs,
         _ => false,
     };
 
-    command_output({
-        let mut cmd = Command::new("docker");
-        cmd.args(["compose", "up", "-d", "retreivr"])
-            .current_dir(app_support_dir(&app));
-        cmd
-    })?;
+    let endpoint = resolved_docker_endpoint(&settings);
+    let restarted_natively = match (docker::connect(endpoint.as_deref()), model.retreivr()) {
+        (Ok(client), Some(service)) => docker::create_and_start(&client, service).await.is_ok(),
+        _ => false,
+    };
 
-    Ok(if updated {
+    if !restarted_natively {
+        command_output({
+            let mut cmd = compose_command(&cli, kind, pinned);
+            cmd.args(["up", "-d", "retreivr"]).current_dir(app_support_dir(&app));
+            cmd
+        })
+        .map_err(|stderr| LauncherError::ComposeFailed { stderr })?;
+    }
+
+    let mut message = if updated {
         "Retreivr image updated and container restarted.".to_string()
     } else {
         "Retreivr image already current; container restart applied.".to_string()
+    };
+
+    if updated && settings.clean_up_old_images {
+        if let Ok(client) = docker::connect(endpoint.as_deref()) {
+            let repo = image_repo(&settings.image);
+            if let Ok(dangling) = docker::dangling_images_for_repo(&client, repo).await {
+                let mut space_reclaimed_bytes = 0u64;
+                for image in dangling {
+                    if docker::remove_image(&client, &image.id).await.is_ok() {
+                        space_reclaimed_bytes += image.size_bytes;
+                    }
+                }
+                if space_reclaimed_bytes > 0 {
+                    message.push_str(&format!(
+                        " Freed {:.1} MB from old images.",
+                        space_reclaimed_bytes as f64 / 1_000_000.0
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(message)
+}
+
+/// Removes dangling images left behind by the configured repo's previous pulls,
+/// returning the ids removed and the space reclaimed.
+#[tauri::command]
+async fn prune_retreivr_images(app: AppHandle) -> Result<ImagePruneReport, LauncherError> {
+    let settings = load_settings(&app);
+    let endpoint = resolved_docker_endpoint(&settings);
+    let client = docker::connect(endpoint.as_deref()).map_err(|_| LauncherError::DockerUnavailable)?;
+    let repo = image_repo(&settings.image);
+
+    let dangling = docker::dangling_images_for_repo(&client, repo)
+        .await
+        .map_err(LauncherError::Other)?;
+
+    let mut removed_image_ids = Vec::new();
+    let mut space_reclaimed_bytes = 0u64;
+    for image in dangling {
+        if docker::remove_image(&client, &image.id).await.is_ok() {
+            removed_image_ids.push(image.id);
+            space_reclaimed_bytes += image.size_bytes;
+        }
+    }
+
+    Ok(ImagePruneReport {
+        removed_image_ids,
+        space_reclaimed_bytes,
     })
 }
 
+/// Reports the on-disk size of the configured Retreivr image, the combined size
+/// of its bind-mounted data/config/downloads/logs/tokens directories, and how
+/// much space pruning dangling images for the configured repo would reclaim.
+#[tauri::command]
+async fn docker_disk_usage(app: AppHandle) -> DiskUsageReport {
+    let settings = load_settings(&app);
+    let endpoint = resolved_docker_endpoint(&settings);
+    let repo = image_repo(&settings.image);
+
+    let (retreivr_image_bytes, dangling) = match docker::connect(endpoint.as_deref()) {
+        Ok(client) => {
+            let image = image_for_channel(&settings.image, settings.release_channel);
+            let size = docker::image_size(&client, &image).await;
+            let dangling = docker::dangling_images_for_repo(&client, repo).await.unwrap_or_default();
+            (size, dangling)
+        }
+        Err(_) => (None, Vec::new()),
+    };
+
+    let retreivr_data_bytes = [
+        &settings.config_dir,
+        &settings.data_dir,
+        &settings.downloads_dir,
+        &settings.logs_dir,
+        &settings.tokens_dir,
+    ]
+    .iter()
+    .map(|dir| dir_size_bytes(&resolve_mount_source(&app, dir)))
+    .sum();
+
+    DiskUsageReport {
+        retreivr_image_bytes,
+        retreivr_data_bytes,
+        dangling_image_count: dangling.len(),
+        reclaimable_bytes: dangling.iter().map(|image| image.size_bytes).sum(),
+    }
+}
+
 #[tauri::command]
 fn docker_available() -> bool {
     command_success({
@@ -838,35 +1345,44 @@ fn get_launcher_settings(app: AppHandle) -> LauncherSettings {
 fn save_launcher_settings(
     app: AppHandle,
     settings: LauncherSettings,
-) -> Result<LauncherSettings, String> {
+) -> Result<LauncherSettings, LauncherError> {
     let normalized = normalize_settings(&settings);
     validate_settings(&normalized)?;
     save_settings_to_disk(&app, &normalized)?;
     ensure_runtime_dirs(&app, &normalized)?;
-    fs::write(compose_path(&app), render_compose(&app, &normalized)).map_err(|e| e.to_string())?;
+    fs::write(compose_path(&app), render_compose(&app, &normalized)?)?;
     Ok(normalized)
 }
 
 #[tauri::command]
-fn reset_launcher_settings(app: AppHandle) -> Result<LauncherSettings, String> {
+fn reset_launcher_settings(app: AppHandle) -> Result<LauncherSettings, LauncherError> {
     let defaults = LauncherSettings::default();
-    fs::create_dir_all(app_support_dir(&app)).map_err(|e| e.to_string())?;
+    fs::create_dir_all(app_support_dir(&app))?;
     save_settings_to_disk(&app, &defaults)?;
-    fs::write(compose_path(&app), render_compose(&app, &defaults)).map_err(|e| e.to_string())?;
+    fs::write(compose_path(&app), render_compose(&app, &defaults)?)?;
     ensure_runtime_dirs(&app, &defaults)?;
     Ok(defaults)
 }
 
 #[tauri::command]
-fn container_running(app: AppHandle) -> bool {
+async fn container_running(app: AppHandle) -> bool {
     if !compose_path(&app).exists() {
         return false;
     }
 
+    let settings = load_settings(&app);
+
+    if let Ok(client) = docker::connect(resolved_docker_endpoint(&settings).as_deref()) {
+        let state = docker::inspect(&client, &settings.container_name).await;
+        if state.exists {
+            return state.running;
+        }
+    }
+
+    let (cli, kind) = runtime_cli_and_kind(&settings);
     command_output({
-        let mut cmd = Command::new("docker");
-        cmd.args(["compose", "ps", "-q"])
-            .current_dir(app_support_dir(&app));
+        let mut cmd = compose_command(&cli, kind, settings.docker_context.as_deref());
+        cmd.args(["ps", "-q"]).current_dir(app_support_dir(&app));
         cmd
     })
     .map(|stdout| !stdout.is_empty())
@@ -874,35 +1390,147 @@ fn container_running(app: AppHandle) -> bool {
 }
 
 #[tauri::command]
-fn docker_diagnostics(app: AppHandle) -> DockerDiagnostics {
+fn docker_contexts(app: AppHandle) -> DockerContextsReport {
+    let settings = load_settings(&app);
+    let cli = runtime_cli(&settings);
+    let contexts = docker_context::list_contexts(&cli).unwrap_or_default();
+    let active = docker_context::resolve_active_context(&cli, settings.docker_context.as_deref());
+    DockerContextsReport { contexts, active }
+}
+
+/// Polls, with exponential backoff, until the Retreivr container is reported
+/// healthy or the web UI responds, tailing recent service logs for
+/// `readiness_marker` along the way. Intended to be called right after
+/// `install_retreivr`/`update_retreivr_and_restart` so onboarding reflects real
+/// startup state instead of "a container id exists".
+#[tauri::command]
+async fn wait_until_ready(
+    app: AppHandle,
+    timeout_ms: Option<u64>,
+    readiness_marker: Option<String>,
+) -> ReadinessReport {
+    let settings = load_settings(&app);
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_READY_TIMEOUT_MS));
+    let marker = readiness_marker
+        .filter(|m| !m.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_READINESS_MARKER.to_string())
+        .to_ascii_lowercase();
+    let endpoint = resolved_docker_endpoint(&settings);
+    let started = Instant::now();
+    let mut poll_delay = Duration::from_millis(DEFAULT_READY_POLL_MS);
+
+    loop {
+        if let Ok(client) = docker::connect(endpoint.as_deref()) {
+            let state = docker::inspect(&client, &settings.container_name).await;
+            if state.exists && !state.running {
+                return ReadinessReport {
+                    state: ReadinessState::Exited,
+                    elapsed_ms: started.elapsed().as_millis() as u64,
+                    detail: "Container exited before becoming ready.".to_string(),
+                };
+            }
+            if state.health.as_deref() == Some("healthy") {
+                return ReadinessReport {
+                    state: ReadinessState::Healthy,
+                    elapsed_ms: started.elapsed().as_millis() as u64,
+                    detail: "Container reported a healthy status.".to_string(),
+                };
+            }
+        }
+
+        if service_reachable(settings.host_port) {
+            return ReadinessReport {
+                state: ReadinessState::Healthy,
+                elapsed_ms: started.elapsed().as_millis() as u64,
+                detail: format!("Web UI responded on port {}.", settings.host_port),
+            };
+        }
+
+        let recent_logs = {
+            let app = app.clone();
+            tokio::task::spawn_blocking(move || {
+                run_compose_with_output(&app, &["logs", "--tail", "50", "retreivr"]).unwrap_or_default()
+            })
+            .await
+            .unwrap_or_default()
+        };
+        if recent_logs.to_ascii_lowercase().contains(&marker) {
+            return ReadinessReport {
+                state: ReadinessState::Healthy,
+                elapsed_ms: started.elapsed().as_millis() as u64,
+                detail: "Found readiness marker in service logs.".to_string(),
+            };
+        }
+
+        let elapsed = started.elapsed();
+        if elapsed >= timeout {
+            return ReadinessReport {
+                state: ReadinessState::TimedOut,
+                elapsed_ms: elapsed.as_millis() as u64,
+                detail: format!("Timed out after {}ms waiting for readiness.", timeout.as_millis()),
+            };
+        }
+
+        tokio::time::sleep(poll_delay.min(timeout - elapsed)).await;
+        poll_delay = (poll_delay * 2).min(Duration::from_millis(MAX_READY_POLL_MS));
+    }
+}
+
+#[tauri::command]
+async fn docker_diagnostics(app: AppHandle) -> DockerDiagnostics {
     let settings = load_settings(&app);
     let runtime_dir = app_support_dir(&app);
     let compose_file = compose_path(&app);
-    let docker_installed = command_success({
-        let mut cmd = Command::new("docker");
-        cmd.arg("--version");
-        cmd
-    });
+    let detected_runtime = runtime::pick_runtime(settings.preferred_runtime.as_deref());
+    let cli = detected_runtime
+        .as_ref()
+        .map(|r| r.cli_path.clone())
+        .unwrap_or_else(|| "docker".to_string());
+    let kind = detected_runtime
+        .as_ref()
+        .map(|r| r.kind)
+        .unwrap_or(runtime::RuntimeKind::Docker);
+
+    let pinned = settings.docker_context.as_deref();
+    let docker_installed = detected_runtime.is_some()
+        || command_success({
+            let mut cmd = Command::new(&cli);
+            docker_context::apply_context_flag(&mut cmd, &cli, pinned);
+            cmd.arg("--version");
+            cmd
+        });
     let docker_running = command_success({
-        let mut cmd = Command::new("docker");
+        let mut cmd = Command::new(&cli);
+        docker_context::apply_context_flag(&mut cmd, &cli, pinned);
         cmd.arg("info");
         cmd
     });
     let compose_available = command_success({
-        let mut cmd = Command::new("docker");
-        cmd.args(["compose", "version"]);
+        let mut cmd = compose_command(&cli, kind, pinned);
+        cmd.arg("version");
         cmd
     });
     let compose_exists = compose_file.exists();
+    let resolved_endpoint = docker_context::resolve_endpoint(&cli, pinned);
+    let active_context = docker_context::resolve_active_context(&cli, pinned);
+    let native_state = match docker::connect(resolved_endpoint.as_deref()) {
+        Ok(client) => {
+            let state = docker::inspect(&client, &settings.container_name).await;
+            state.exists.then_some(state.running)
+        }
+        Err(_) => None,
+    };
     let container_running = docker_running
         && compose_exists
-        && command_output({
-            let mut cmd = Command::new("docker");
-            cmd.args(["compose", "ps", "-q"]).current_dir(&runtime_dir);
-            cmd
-        })
-        .map(|stdout| !stdout.is_empty())
-        .unwrap_or(false);
+        && native_state.unwrap_or_else(|| {
+            command_output({
+                let mut cmd = compose_command(&cli, kind, pinned);
+                cmd.args(["ps", "-q"]).current_dir(&runtime_dir);
+                cmd
+            })
+            .map(|stdout| !stdout.is_empty())
+            .unwrap_or(false)
+        });
 
     let service_reachable = container_running && service_reachable(settings.host_port);
     let last_error = diagnostics_failure_message(
@@ -924,6 +1552,9 @@ fn docker_diagnostics(app: AppHandle) -> DockerDiagnostics {
         web_url: web_url(&settings),
         compose_path: compose_file.to_string_lossy().to_string(),
         runtime_dir: runtime_dir.to_string_lossy().to_string(),
+        detected_runtime: detected_runtime.map(|r| format!("{:?}", r.kind).to_lowercase()),
+        active_context,
+        resolved_endpoint,
         last_error,
     }
 }
@@ -955,7 +1586,9 @@ fn preflight_start_checks(app: AppHandle) -> PreflightReport {
                 details: e.to_string(),
                 fix: "Ensure your user can write to the launcher app-data directory.".to_string(),
             });
-        } else if let Err(e) = fs::write(compose_path(&app), render_compose(&app, &settings)) {
+        } else if let Err(e) = render_compose(&app, &settings)
+            .and_then(|yaml| fs::write(compose_path(&app), yaml).map_err(LauncherError::from))
+        {
             checks.push(PreflightCheck {
                 key: "compose_render".to_string(),
                 label: "Compose generation".to_string(),
@@ -974,11 +1607,15 @@ fn preflight_start_checks(app: AppHandle) -> PreflightReport {
         }
     }
 
-    let docker_installed = command_success({
-        let mut cmd = Command::new("docker");
-        cmd.arg("--version");
-        cmd
-    });
+    let cli = runtime_cli(&settings);
+    let pinned = settings.docker_context.as_deref();
+    let docker_installed = runtime::pick_runtime(settings.preferred_runtime.as_deref()).is_some()
+        || command_success({
+            let mut cmd = Command::new(&cli);
+            docker_context::apply_context_flag(&mut cmd, &cli, pinned);
+            cmd.arg("--version");
+            cmd
+        });
     checks.push(PreflightCheck {
         key: "docker_installed".to_string(),
         label: "Docker CLI available".to_string(),
@@ -992,7 +1629,8 @@ fn preflight_start_checks(app: AppHandle) -> PreflightReport {
     });
 
     let docker_running = command_success({
-        let mut cmd = Command::new("docker");
+        let mut cmd = Command::new(&cli);
+        docker_context::apply_context_flag(&mut cmd, &cli, pinned);
         cmd.arg("info");
         cmd
     });
@@ -1008,7 +1646,7 @@ fn preflight_start_checks(app: AppHandle) -> PreflightReport {
         fix: "Start Docker Desktop and wait for engine startup.".to_string(),
     });
 
-    let docker_permissions = run_compose_with_output(&app, &["compose", "version"]).is_ok();
+    let docker_permissions = run_compose_with_output(&app, &["version"]).is_ok();
     checks.push(PreflightCheck {
         key: "docker_permissions".to_string(),
         label: "Docker compose access".to_string(),
@@ -1034,7 +1672,7 @@ fn preflight_start_checks(app: AppHandle) -> PreflightReport {
         fix: "Choose another host port in configuration and save.".to_string(),
     });
 
-    let compose_valid = run_compose_with_output(&app, &["compose", "config"]).is_ok();
+    let compose_valid = run_compose_with_output(&app, &["config"]).is_ok();
     checks.push(PreflightCheck {
         key: "compose_valid".to_string(),
         label: "Compose file validation".to_string(),
@@ -1052,8 +1690,8 @@ fn preflight_start_checks(app: AppHandle) -> PreflightReport {
 }
 
 #[tauri::command]
-fn onboarding_checklist(app: AppHandle) -> OnboardingChecklist {
-    let diagnostics = docker_diagnostics(app.clone());
+async fn onboarding_checklist(app: AppHandle) -> OnboardingChecklist {
+    let diagnostics = docker_diagnostics(app.clone()).await;
     let settings_saved = settings_path(&app).exists();
 
     let items = vec![
@@ -1130,37 +1768,84 @@ fn browse_for_directory() -> Result<Option<String>, String> {
 #[tauri::command]
 fn view_retreivr_logs(app: AppHandle, lines: Option<u32>) -> Result<String, String> {
     let tail = lines.unwrap_or(200).clamp(20, 2000).to_string();
-    run_compose_with_output(&app, &["compose", "logs", "--tail", &tail, "retreivr"])
+    run_compose_with_output(&app, &["logs", "--tail", &tail, "retreivr"])
 }
 
 #[tauri::command]
-fn install_retreivr(app: AppHandle) -> Result<(), String> {
+async fn install_retreivr(app: AppHandle) -> Result<(), LauncherError> {
     let dir = app_support_dir(&app);
     let compose = compose_path(&app);
     let settings = load_settings(&app);
 
     validate_settings(&settings)?;
-    fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    if !engine_available(&settings) {
+        return Err(LauncherError::DockerUnavailable);
+    }
+    if !host_port_available(settings.host_port) {
+        return Err(LauncherError::PortInUse(settings.host_port));
+    }
+    fs::create_dir_all(&dir)?;
     ensure_runtime_dirs(&app, &settings)?;
-    fs::write(&compose, render_compose(&app, &settings)).map_err(|e| e.to_string())?;
+    let model = compose_model(&app, &settings);
+    fs::write(&compose, model.to_yaml().map_err(LauncherError::Other)?)?;
+
+    if let Some(service) = model.retreivr() {
+        if let Ok(client) = docker::connect(resolved_docker_endpoint(&settings).as_deref()) {
+            let candidates = mirrored_image_candidates(&service.image, &settings.image_mirrors);
+            let mut pulled_as = None;
+            for candidate in &candidates {
+                if docker::pull_image(&client, candidate).await.is_ok() {
+                    pulled_as = Some(candidate.clone());
+                    break;
+                }
+            }
 
+            let tagged = match pulled_as {
+                Some(ref pulled) if pulled == &service.image => true,
+                Some(ref pulled) => docker::tag_image(&client, pulled, &service.image).await.is_ok(),
+                None => false,
+            };
+
+            if tagged && docker::create_and_start(&client, service).await.is_ok() {
+                return Ok(());
+            }
+        }
+    }
+
+    // Fall back to CLI compose when the native engine API isn't reachable (e.g. a
+    // remote context only the CLI knows how to resolve).
+    let (cli, kind) = runtime_cli_and_kind(&settings);
     command_output({
-        let mut cmd = Command::new("docker");
-        cmd.args(["compose", "up", "-d"]).current_dir(&dir);
+        let mut cmd = compose_command(&cli, kind, settings.docker_context.as_deref());
+        cmd.args(["up", "-d"]).current_dir(&dir);
         cmd
-    })?;
+    })
+    .map_err(|stderr| LauncherError::ComposeFailed { stderr })?;
 
     Ok(())
 }
 
 #[tauri::command]
-fn stop_retreivr(app: AppHandle) -> Result<(), String> {
+async fn stop_retreivr(app: AppHandle) -> Result<(), LauncherError> {
+    let settings = load_settings(&app);
+
+    if let Ok(client) = docker::connect(resolved_docker_endpoint(&settings).as_deref()) {
+        if docker::stop(&client, &settings.container_name).await.is_ok() {
+            return Ok(());
+        }
+    }
+
+    if !engine_available(&settings) {
+        return Err(LauncherError::DockerUnavailable);
+    }
+
+    let (cli, kind) = runtime_cli_and_kind(&settings);
     command_output({
-        let mut cmd = Command::new("docker");
-        cmd.args(["compose", "down"])
-            .current_dir(app_support_dir(&app));
+        let mut cmd = compose_command(&cli, kind, settings.docker_context.as_deref());
+        cmd.args(["down"]).current_dir(app_support_dir(&app));
         cmd
-    })?;
+    })
+    .map_err(|stderr| LauncherError::ComposeFailed { stderr })?;
 
     Ok(())
 }
@@ -1168,12 +1853,26 @@ fn stop_retreivr(app: AppHandle) -> Result<(), String> {
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
+        .manage(streaming::StreamRegistry::default())
+        .setup(|app| {
+            let handle = app.handle().clone();
+            let settings = load_settings_from_disk(&handle);
+            app.manage(SettingsState(Mutex::new(settings)));
+            Ok(())
+        })
         .invoke_handler(tauri::generate_handler![
             docker_available,
             compose_exists,
             install_guidance,
             launcher_version_info,
+            download_and_apply_launcher_update,
             check_retreivr_image_update,
+            stream_pull_retreivr_image,
+            stream_compose_up,
+            stream_compose_down,
+            cancel_stream,
+            start_log_stream,
+            stop_log_stream,
             update_retreivr_and_restart,
             get_launcher_settings,
             save_launcher_settings,
@@ -1186,6 +1885,10 @@ pub fn run() {
             view_retreivr_logs,
             container_running,
             docker_diagnostics,
+            docker_contexts,
+            wait_until_ready,
+            prune_retreivr_images,
+            docker_disk_usage,
             install_retreivr,
             stop_retreivr
         ])
@@ -0,0 +1,247 @@
+//! Discovery of the container runtime/CLI the launcher should drive, since not every
+//! user runs plain Docker Desktop.
+
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum RuntimeKind {
+    Docker,
+    Podman,
+    Colima,
+    OrbStack,
+    Nerdctl,
+}
+
+impl RuntimeKind {
+    pub(crate) fn cli_name(self) -> &'static str {
+        match self {
+            RuntimeKind::Docker | RuntimeKind::Colima | RuntimeKind::OrbStack => "docker",
+            RuntimeKind::Podman => "podman",
+            RuntimeKind::Nerdctl => "nerdctl",
+        }
+    }
+
+    /// Subcommand prefix used to invoke compose through this runtime's CLI.
+    pub(crate) fn compose_subcommand(self) -> &'static [&'static str] {
+        match self {
+            RuntimeKind::Podman => &["compose"],
+            RuntimeKind::Nerdctl => &["compose"],
+            _ => &["compose"],
+        }
+    }
+
+    fn all() -> [RuntimeKind; 5] {
+        [
+            RuntimeKind::OrbStack,
+            RuntimeKind::Colima,
+            RuntimeKind::Podman,
+            RuntimeKind::Nerdctl,
+            RuntimeKind::Docker,
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct DetectedRuntime {
+    pub(crate) kind: RuntimeKind,
+    pub(crate) cli_path: String,
+}
+
+fn path_entries() -> Vec<PathBuf> {
+    env::var("PATH")
+        .unwrap_or_default()
+        .split(if cfg!(windows) { ';' } else { ':' })
+        .filter(|entry| !entry.is_empty())
+        .map(PathBuf::from)
+        .collect()
+}
+
+/// Homebrew installs CLIs under different prefixes depending on host architecture:
+/// Apple Silicon uses `/opt/homebrew`, Intel Macs use `/usr/local`. Probe both rather
+/// than assuming one.
+fn homebrew_candidates(cli_name: &str) -> [PathBuf; 2] {
+    [
+        Path::new("/opt/homebrew/bin").join(cli_name),
+        Path::new("/usr/local/bin").join(cli_name),
+    ]
+}
+
+fn search_path_for(cli_name: &str) -> Option<PathBuf> {
+    for dir in path_entries() {
+        let candidate = dir.join(cli_name);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
+    None
+}
+
+#[cfg(target_os = "macos")]
+fn macos_app_bundle_present(app_name: &str) -> bool {
+    Path::new("/Applications").join(app_name).exists()
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME").or_else(|| env::var_os("USERPROFILE")).map(PathBuf::from)
+}
+
+/// Colima is driven through the same `docker` CLI as plain Docker Desktop (against
+/// a context Colima itself manages), so the only way to tell them apart is Colima's
+/// own `colima` binary or the `~/.colima` state directory it creates on first run.
+fn colima_marker_present() -> bool {
+    homebrew_candidates("colima").into_iter().any(|p| p.is_file())
+        || search_path_for("colima").is_some()
+        || home_dir().is_some_and(|home| home.join(".colima").is_dir())
+}
+
+/// OrbStack ships its own `docker` CLI shim, so beyond its (macOS-only) app bundle
+/// the only reliable marker is the Unix socket it exposes under `~/.orbstack`.
+fn orbstack_marker_present() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        if macos_app_bundle_present("OrbStack.app") {
+            return true;
+        }
+    }
+    home_dir().is_some_and(|home| home.join(".orbstack").join("run").join("docker.sock").exists())
+}
+
+#[cfg(target_os = "windows")]
+fn windows_registry_install_dir(display_name_substring: &str) -> Option<PathBuf> {
+    // Mirrors the rest of the launcher's approach of shelling out to a trusted host
+    // tool rather than bundling a registry-access crate.
+    let script = format!(
+        "Get-ChildItem 'HKLM:\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Uninstall' | \
+         Get-ItemProperty | Where-Object {{ $_.DisplayName -like '*{display_name_substring}*' }} | \
+         Select-Object -First 1 -ExpandProperty InstallLocation"
+    );
+    let output = Command::new("powershell")
+        .args(["-NoProfile", "-Command", &script])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if value.is_empty() {
+        None
+    } else {
+        Some(PathBuf::from(value))
+    }
+}
+
+fn locate_cli(kind: RuntimeKind) -> Option<PathBuf> {
+    // Colima/OrbStack alias `cli_name()` to plain `docker`, since that's the CLI
+    // actually used to drive them; gate on their own markers first so a bare
+    // `docker` on PATH isn't misreported as both of these plus plain Docker.
+    match kind {
+        RuntimeKind::Colima if !colima_marker_present() => return None,
+        RuntimeKind::OrbStack if !orbstack_marker_present() => return None,
+        _ => {}
+    }
+
+    let cli_name = kind.cli_name();
+
+    #[cfg(target_os = "macos")]
+    {
+        let app_present = match kind {
+            RuntimeKind::Docker => macos_app_bundle_present("Docker.app"),
+            RuntimeKind::OrbStack => macos_app_bundle_present("OrbStack.app"),
+            RuntimeKind::Podman => macos_app_bundle_present("Podman Desktop.app"),
+            RuntimeKind::Colima | RuntimeKind::Nerdctl => false,
+        };
+
+        if app_present {
+            if let Some(found) = homebrew_candidates(cli_name)
+                .into_iter()
+                .find(|p| p.is_file())
+                .or_else(|| search_path_for(cli_name))
+            {
+                return Some(found);
+            }
+            // App is installed but its CLI shim isn't on a known prefix yet; fall
+            // back to a full PATH scan below.
+        }
+
+        for candidate in homebrew_candidates(cli_name) {
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+        search_path_for(cli_name)
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        let display_name = match kind {
+            RuntimeKind::Docker => "Docker Desktop",
+            RuntimeKind::Podman => "Podman",
+            RuntimeKind::OrbStack => "OrbStack",
+            RuntimeKind::Colima | RuntimeKind::Nerdctl => return search_path_for(cli_name),
+        };
+
+        if let Some(install_dir) = windows_registry_install_dir(display_name) {
+            let candidate = install_dir.join(format!("{cli_name}.exe"));
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+        search_path_for(cli_name)
+    }
+
+    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    {
+        search_path_for(cli_name)
+    }
+}
+
+fn is_docker_like_healthy(cli_path: &Path) -> bool {
+    Command::new(cli_path)
+        .arg("info")
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+/// Probes for every supported container runtime and returns the ones whose CLI was
+/// found on this machine, most-specific first (OrbStack/Colima/Podman/Nerdctl before
+/// plain Docker, since those all ship or shim a `docker` binary too).
+pub(crate) fn discover_runtimes() -> Vec<DetectedRuntime> {
+    let mut found = Vec::new();
+    for kind in RuntimeKind::all() {
+        if let Some(cli_path) = locate_cli(kind) {
+            found.push(DetectedRuntime {
+                kind,
+                cli_path: cli_path.to_string_lossy().to_string(),
+            });
+        }
+    }
+    found
+}
+
+/// Picks the runtime to use: the user's pinned preference if it was actually
+/// detected, otherwise the first healthy runtime found, otherwise the first
+/// detected runtime (so diagnostics still has something to report against).
+pub(crate) fn pick_runtime(preferred: Option<&str>) -> Option<DetectedRuntime> {
+    let detected = discover_runtimes();
+
+    if let Some(preferred) = preferred {
+        if let Some(found) = detected
+            .iter()
+            .find(|r| r.kind.cli_name() == preferred || format!("{:?}", r.kind).eq_ignore_ascii_case(preferred))
+        {
+            return Some(found.clone());
+        }
+    }
+
+    detected
+        .iter()
+        .find(|r| is_docker_like_healthy(Path::new(&r.cli_path)))
+        .or_else(|| detected.first())
+        .cloned()
+}
@@ -0,0 +1,132 @@
+//! Cleans up the environment inherited from a Linux sandbox (Flatpak, Snap,
+//! AppImage) before spawning host-side tools like `docker`, `xdg-open`, and
+//! `zenity`, which otherwise inherit a PATH/library path pointed at the sandbox
+//! runtime instead of the host.
+
+use std::env;
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SandboxKind {
+    Flatpak,
+    Snap,
+    AppImage,
+}
+
+impl SandboxKind {
+    /// Path-list prefixes this sandbox injects that should never reach a
+    /// host-spawned process.
+    fn polluted_prefixes(self) -> &'static [&'static str] {
+        match self {
+            SandboxKind::Flatpak => &["/app/", "/app/lib", "/usr/lib/extensions"],
+            SandboxKind::Snap => &["/snap/", "/var/lib/snapd/"],
+            SandboxKind::AppImage => &["/tmp/.mount_"],
+        }
+    }
+
+    /// Variables the sandbox runtime sets that make no sense for a host process
+    /// and should be unset entirely rather than forwarded blank.
+    fn vars_to_unset(self) -> &'static [&'static str] {
+        match self {
+            SandboxKind::Flatpak => &[
+                "LD_LIBRARY_PATH",
+                "GST_PLUGIN_SYSTEM_PATH",
+                "GST_PLUGIN_SCANNER",
+                "GTK_PATH",
+            ],
+            SandboxKind::Snap => &["LD_LIBRARY_PATH", "GST_PLUGIN_SYSTEM_PATH", "GTK_PATH"],
+            SandboxKind::AppImage => &["LD_LIBRARY_PATH"],
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+pub(crate) fn detect_sandbox() -> Option<SandboxKind> {
+    if env::var_os("FLATPAK_ID").is_some() {
+        return Some(SandboxKind::Flatpak);
+    }
+    if env::var_os("SNAP").is_some() || env::var_os("SNAP_NAME").is_some() {
+        return Some(SandboxKind::Snap);
+    }
+    if env::var_os("APPIMAGE").is_some() || env::var_os("APPDIR").is_some() {
+        return Some(SandboxKind::AppImage);
+    }
+    None
+}
+
+#[cfg(not(target_os = "linux"))]
+pub(crate) fn detect_sandbox() -> Option<SandboxKind> {
+    None
+}
+
+/// Splits a colon-delimited path list, drops entries under any of `dropped_prefixes`,
+/// and deduplicates while preferring the *later* occurrence of a repeated path so
+/// host-restored entries (appended after the sandbox ones) win.
+pub(crate) fn normalize_pathlist(list: &str, dropped_prefixes: &[&str]) -> String {
+    let entries: Vec<&str> = list
+        .split(':')
+        .filter(|entry| !entry.is_empty())
+        .filter(|entry| !dropped_prefixes.iter().any(|prefix| entry.starts_with(prefix)))
+        .collect();
+
+    let mut deduped: Vec<&str> = Vec::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        let last_occurrence = entries[i + 1..].iter().all(|later| later != entry);
+        if last_occurrence && !deduped.contains(entry) {
+            deduped.push(entry);
+        }
+    }
+
+    deduped.join(":")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::normalize_pathlist;
+
+    #[test]
+    fn normalize_pathlist_drops_sandbox_prefixes() {
+        let list = "/app/bin:/usr/bin:/app/lib/bin";
+        assert_eq!(normalize_pathlist(list, &["/app/"]), "/usr/bin");
+    }
+
+    #[test]
+    fn normalize_pathlist_dedupes_preferring_later_occurrence() {
+        let list = "/usr/bin:/usr/local/bin:/usr/bin";
+        assert_eq!(normalize_pathlist(list, &[]), "/usr/local/bin:/usr/bin");
+    }
+}
+
+fn host_path_fallback() -> String {
+    "/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin".to_string()
+}
+
+/// Rewrites `cmd`'s environment to look like it was launched from outside the
+/// sandbox, if one is detected. A no-op on non-Linux or unsandboxed runs.
+pub(crate) fn normalize_host_env(cmd: &mut Command) {
+    let Some(sandbox) = detect_sandbox() else {
+        return;
+    };
+
+    let prefixes = sandbox.polluted_prefixes();
+
+    let current_path = env::var("PATH").unwrap_or_default();
+    let path = normalize_pathlist(&format!("{current_path}:{}", host_path_fallback()), prefixes);
+    cmd.env("PATH", path);
+
+    if let Ok(xdg_data_dirs) = env::var("XDG_DATA_DIRS") {
+        let cleaned = normalize_pathlist(&xdg_data_dirs, prefixes);
+        if !cleaned.is_empty() {
+            cmd.env("XDG_DATA_DIRS", cleaned);
+        }
+    }
+
+    for var in sandbox.vars_to_unset() {
+        // Sandboxes sometimes export these as empty strings rather than leaving
+        // them unset, which is just as misleading to the host process.
+        let is_polluted = env::var(var).map(|v| v.is_empty() || prefixes.iter().any(|p| v.starts_with(p))).unwrap_or(false);
+        if is_polluted || env::var_os(var).is_some() {
+            cmd.env_remove(var);
+        }
+    }
+}
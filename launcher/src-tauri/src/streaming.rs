@@ -0,0 +1,250 @@
+//! Streaming variants of the long-running `docker pull` / `compose up`/`down`
+//! invocations, so the UI can render real progress instead of waiting on
+//! `Command::output()`. Short commands keep using the synchronous helpers in
+//! `lib.rs`; this module is only for operations worth showing live output for
+//! (progress bars for pull/compose, and the followed service log).
+
+use std::collections::HashMap;
+use std::io::{BufRead, BufReader};
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+
+use crate::with_runtime_path;
+
+/// Tracks child processes spawned for streaming so a stream id can be cancelled.
+#[derive(Default)]
+pub(crate) struct StreamRegistry(Mutex<HashMap<String, Child>>);
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct StreamProgressEvent {
+    stream_id: String,
+    layer_id: Option<String>,
+    status: String,
+    percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct StreamDoneEvent {
+    stream_id: String,
+    success: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct LogLineEvent {
+    stream_id: String,
+    line: String,
+}
+
+/// Parses a single line of `docker pull` output, e.g.
+/// `a1b2c3d4: Downloading [=====>     ] 12.3MB/45.6MB`, into a layer id, a status
+/// phrase, and a completion percentage when one can be computed.
+fn parse_pull_progress_line(line: &str) -> (Option<String>, String, Option<f64>) {
+    let (layer_id, rest) = match line.split_once(": ") {
+        // Docker layer progress lines are prefixed with a short hex digest, e.g.
+        // `a1b2c3d4: Downloading ...`; status lines like `Status: ...` aren't hex.
+        Some((id, rest)) if !id.is_empty() && id.chars().all(|c| c.is_ascii_hexdigit()) => {
+            (Some(id.to_string()), rest)
+        }
+        _ => (None, line),
+    };
+
+    let percent = rest.split_once('[').and_then(|(_, after)| {
+        let bytes_part = after.split(']').nth(1)?.trim();
+        let (current, total) = bytes_part.split_once('/')?;
+        let current = parse_byte_size(current.trim())?;
+        let total = parse_byte_size(total.trim())?;
+        if total <= 0.0 {
+            None
+        } else {
+            Some((current / total * 100.0).clamp(0.0, 100.0))
+        }
+    });
+
+    (layer_id, rest.trim().to_string(), percent)
+}
+
+fn parse_byte_size(value: &str) -> Option<f64> {
+    let value = value.trim();
+    let split_at = value.find(|c: char| !c.is_ascii_digit() && c != '.')?;
+    let (number, unit) = value.split_at(split_at);
+    let number: f64 = number.parse().ok()?;
+    let multiplier = match unit {
+        "B" => 1.0,
+        "kB" | "KB" => 1_000.0,
+        "MB" => 1_000_000.0,
+        "GB" => 1_000_000_000.0,
+        _ => return None,
+    };
+    Some(number * multiplier)
+}
+
+/// Spawns `cmd` with piped stdout/stderr, forwarding each line as a `retreivr://*`
+/// progress event tagged with `stream_id`, and registers the child so it can later
+/// be cancelled via [`cancel_stream`].
+pub(crate) fn spawn_streamed(
+    app: &AppHandle,
+    stream_id: String,
+    event_name: &'static str,
+    mut cmd: Command,
+) -> Result<(), String> {
+    with_runtime_path(&mut cmd);
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+
+    for pipe in [child.stdout.take().map(Either::Stdout), child.stderr.take().map(Either::Stderr)]
+        .into_iter()
+        .flatten()
+    {
+        let app_handle = app.clone();
+        let sid = stream_id.clone();
+        thread::spawn(move || {
+            let lines: Box<dyn Iterator<Item = std::io::Result<String>>> = match pipe {
+                Either::Stdout(out) => Box::new(BufReader::new(out).lines()),
+                Either::Stderr(err) => Box::new(BufReader::new(err).lines()),
+            };
+            for line in lines.map_while(Result::ok) {
+                let (layer_id, status, percent) = parse_pull_progress_line(&line);
+                let _ = app_handle.emit(
+                    event_name,
+                    StreamProgressEvent {
+                        stream_id: sid.clone(),
+                        layer_id,
+                        status,
+                        percent,
+                    },
+                );
+            }
+        });
+    }
+
+    app.state::<StreamRegistry>()
+        .0
+        .lock()
+        .map_err(|_| "stream registry poisoned".to_string())?
+        .insert(stream_id, child);
+
+    Ok(())
+}
+
+enum Either<O, E> {
+    Stdout(O),
+    Stderr(E),
+}
+
+/// Follows `cmd`'s stdout/stderr line-by-line, emitting each as a
+/// `retreivr://log-line` event tagged with `stream_id` instead of buffering and
+/// returning one clamped tail, so `start_log_stream`/`stop_log_stream` can give
+/// the frontend a live-tailing view.
+pub(crate) fn spawn_log_stream(
+    app: &AppHandle,
+    stream_id: String,
+    mut cmd: Command,
+) -> Result<(), String> {
+    with_runtime_path(&mut cmd);
+    cmd.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    let mut child = cmd.spawn().map_err(|e| e.to_string())?;
+
+    for pipe in [child.stdout.take().map(Either::Stdout), child.stderr.take().map(Either::Stderr)]
+        .into_iter()
+        .flatten()
+    {
+        let app_handle = app.clone();
+        let sid = stream_id.clone();
+        thread::spawn(move || {
+            let lines: Box<dyn Iterator<Item = std::io::Result<String>>> = match pipe {
+                Either::Stdout(out) => Box::new(BufReader::new(out).lines()),
+                Either::Stderr(err) => Box::new(BufReader::new(err).lines()),
+            };
+            for line in lines.map_while(Result::ok) {
+                let _ = app_handle.emit(
+                    "retreivr://log-line",
+                    LogLineEvent {
+                        stream_id: sid.clone(),
+                        line,
+                    },
+                );
+            }
+        });
+    }
+
+    app.state::<StreamRegistry>()
+        .0
+        .lock()
+        .map_err(|_| "stream registry poisoned".to_string())?
+        .insert(stream_id, child);
+
+    Ok(())
+}
+
+/// Waits for the stream's child process to exit and emits a terminal
+/// `retreivr://stream-done` event. Call this from a background task right after
+/// `spawn_streamed` if the caller needs to know when the operation finished.
+pub(crate) fn await_completion(app: &AppHandle, stream_id: String) {
+    let app_handle = app.clone();
+    thread::spawn(move || {
+        // Poll with `try_wait()` and drop the lock between polls instead of
+        // blocking on `wait()`, so a concurrent `cancel_stream` can still find and
+        // kill the child instead of blocking on this same mutex until it exits.
+        let success = loop {
+            let mut registry = match app_handle.state::<StreamRegistry>().0.lock() {
+                Ok(guard) => guard,
+                Err(_) => return,
+            };
+            // Gone from the registry means `cancel_stream` took and killed it.
+            let Some(child) = registry.get_mut(&stream_id) else { break false };
+            match child.try_wait() {
+                Ok(None) => {}
+                result => {
+                    registry.remove(&stream_id);
+                    break result.ok().flatten().is_some_and(|status| status.success());
+                }
+            }
+            drop(registry);
+            thread::sleep(Duration::from_millis(100));
+        };
+        let _ = app_handle.emit("retreivr://stream-done", StreamDoneEvent { stream_id, success });
+    });
+}
+
+/// Kills the child process registered under `stream_id`, if still running.
+pub(crate) fn cancel_stream(app: &AppHandle, stream_id: &str) -> Result<(), String> {
+    let mut registry = app
+        .state::<StreamRegistry>()
+        .0
+        .lock()
+        .map_err(|_| "stream registry poisoned".to_string())?;
+
+    match registry.remove(stream_id) {
+        Some(mut child) => child.kill().map_err(|e| e.to_string()),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_pull_progress_line;
+
+    #[test]
+    fn parses_layer_id_and_percent() {
+        let (layer_id, status, percent) =
+            parse_pull_progress_line("a1b2c3d4: Downloading [=====>     ] 12MB/45.6MB");
+        assert_eq!(layer_id.as_deref(), Some("a1b2c3d4"));
+        assert!(status.contains("Downloading"));
+        assert!(percent.unwrap() > 0.0 && percent.unwrap() < 100.0);
+    }
+
+    #[test]
+    fn falls_back_when_no_layer_id() {
+        let (layer_id, status, percent) = parse_pull_progress_line("Status: Downloaded newer image");
+        assert_eq!(layer_id, None);
+        assert_eq!(status, "Status: Downloaded newer image");
+        assert_eq!(percent, None);
+    }
+}
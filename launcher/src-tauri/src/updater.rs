@@ -0,0 +1,170 @@
+//! Minisign-verified downloads for `launcher-v*` GitHub releases.
+
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use minisign_verify::{PublicKey, Signature};
+use serde::Deserialize;
+use tauri::AppHandle;
+
+use crate::{
+    app_support_dir, command_output, fetch_latest_launcher_release, load_settings,
+    normalize_release_tag, with_runtime_path,
+};
+
+/// Trusted minisign public key for `launcher-v*` release assets, generated with
+/// `minisign -G` and published alongside the signing key's key-id.
+const LAUNCHER_UPDATE_PUBLIC_KEY: &str =
+    "RWQf6LRCGA9i5aOq2Bi/qv9NXuEyS8lgYUTNEaNwpVp8GIsqTLA/CNRN";
+
+fn releases_api_url(tag: &str) -> String {
+    format!("https://api.github.com/repos/sudostacks/retreivr/releases/tags/{tag}")
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubReleaseAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct GithubRelease {
+    tag_name: String,
+    assets: Vec<GithubReleaseAsset>,
+}
+
+fn platform_asset_suffix() -> Result<&'static str, String> {
+    match env::consts::OS {
+        "macos" => Ok(".dmg"),
+        "windows" => Ok(".msi"),
+        "linux" => Ok(".AppImage"),
+        other => Err(format!("no launcher update artifact available for platform '{other}'")),
+    }
+}
+
+fn fetch_release(tag: &str) -> Result<GithubRelease, String> {
+    let json = command_output({
+        let mut cmd = Command::new("curl");
+        cmd.args([
+            "-fsSL",
+            "-H",
+            "User-Agent: retreivr-launcher",
+            &releases_api_url(tag),
+        ]);
+        cmd
+    })?;
+    serde_json::from_str(&json).map_err(|e| e.to_string())
+}
+
+fn pick_asset<'a>(release: &'a GithubRelease, suffix: &str) -> Option<&'a GithubReleaseAsset> {
+    release
+        .assets
+        .iter()
+        .find(|asset| asset.name.ends_with(suffix) && !asset.name.ends_with(".minisig"))
+}
+
+fn pick_signature_asset<'a>(
+    release: &'a GithubRelease,
+    artifact_name: &str,
+) -> Option<&'a GithubReleaseAsset> {
+    let expected = format!("{artifact_name}.minisig");
+    release.assets.iter().find(|asset| asset.name == expected)
+}
+
+fn download_to(url: &str, dest: &PathBuf) -> Result<(), String> {
+    let mut cmd = Command::new("curl");
+    cmd.args(["-fsSL", "-o"]).arg(dest).arg(url);
+    with_runtime_path(&mut cmd);
+    command_output(cmd).map(|_| ())
+}
+
+fn verify_signature(artifact: &[u8], signature_text: &str) -> Result<(), String> {
+    let public_key = PublicKey::from_base64(LAUNCHER_UPDATE_PUBLIC_KEY)
+        .map_err(|e| format!("invalid embedded public key: {e}"))?;
+    let signature =
+        Signature::decode(signature_text).map_err(|e| format!("malformed signature file: {e}"))?;
+
+    // `verify` itself rejects a key-id mismatch between the signature and `public_key`,
+    // as well as a truncated/corrupted artifact.
+    public_key
+        .verify(artifact, &signature, false)
+        .map_err(|_| "update signature verification failed (key-id mismatch or corrupted download)".to_string())
+}
+
+fn apply_artifact(path: &PathBuf) -> Result<(), String> {
+    #[cfg(target_os = "macos")]
+    {
+        command_output({
+            let mut cmd = Command::new("open");
+            cmd.arg(path);
+            cmd
+        })
+        .map(|_| ())
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        command_output({
+            let mut cmd = Command::new("msiexec");
+            cmd.args(["/i"]).arg(path);
+            cmd
+        })
+        .map(|_| ())
+    }
+
+    #[cfg(all(not(target_os = "macos"), not(target_os = "windows")))]
+    {
+        command_output({
+            let mut cmd = Command::new("chmod");
+            cmd.args(["+x"]).arg(path);
+            cmd
+        })?;
+        command_output({
+            let mut cmd = Command::new("xdg-open");
+            cmd.arg(path);
+            cmd
+        })
+        .map(|_| ())
+    }
+}
+
+/// Resolves the latest `launcher-v*` release for the configured channel, downloads
+/// the asset matching the current platform, verifies it against the embedded
+/// minisign public key, and only then hands it to the OS.
+pub(crate) async fn download_and_apply_update(app: &AppHandle) -> Result<String, String> {
+    let channel = load_settings(app).release_channel;
+    let latest = fetch_latest_launcher_release(channel)?
+        .ok_or_else(|| "no launcher release found for the configured channel".to_string())?;
+    let tag = latest.tag_name;
+    let suffix = platform_asset_suffix()?;
+
+    let release = fetch_release(&tag)?;
+    let artifact = pick_asset(&release, suffix)
+        .ok_or_else(|| format!("no {suffix} asset found in release {}", release.tag_name))?;
+    let signature_asset = pick_signature_asset(&release, &artifact.name)
+        .ok_or_else(|| format!("no .minisig signature found for {}", artifact.name))?;
+
+    let staging_dir = app_support_dir(app).join("updates");
+    fs::create_dir_all(&staging_dir).map_err(|e| e.to_string())?;
+    let artifact_path = staging_dir.join(&artifact.name);
+    let signature_path = staging_dir.join(&signature_asset.name);
+
+    download_to(&artifact.browser_download_url, &artifact_path)?;
+    download_to(&signature_asset.browser_download_url, &signature_path)?;
+
+    let artifact_bytes = fs::read(&artifact_path).map_err(|e| e.to_string())?;
+    if artifact_bytes.is_empty() {
+        return Err("downloaded update artifact is empty (truncated download)".to_string());
+    }
+    let signature_text = fs::read_to_string(&signature_path).map_err(|e| e.to_string())?;
+
+    verify_signature(&artifact_bytes, &signature_text)?;
+    apply_artifact(&artifact_path)?;
+
+    Ok(format!(
+        "Verified and applied launcher update {}.",
+        normalize_release_tag(&release.tag_name)
+    ))
+}